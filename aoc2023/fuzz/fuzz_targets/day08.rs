@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc2023::day08;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day08::input_generator(input);
+    }
+});
@@ -0,0 +1,107 @@
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+#[aoc_generator(day1)]
+pub fn input_generator(input: &str) -> anyhow::Result<Vec<String>> {
+    Ok(parse::normalise(input)
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>())
+}
+
+#[aoc(day1, part1)]
+pub fn part1(input: &[String]) -> Answer {
+    let sum = input
+        .iter()
+        .map(|line| {
+            let a = line
+                .chars()
+                .filter(|c| c.is_ascii_digit())
+                .collect::<Vec<_>>();
+            let first = a.first().unwrap();
+            let last = a.last().unwrap();
+            let value = format!("{}{}", first, last).parse::<u64>().unwrap();
+            tracing::debug!(line = line.as_str(), value, "calibration value");
+            value
+        })
+        .try_fold(0u64, |sum, v| sum.checked_add(v))
+        .expect("Sum of calibration values overflowed");
+    Answer::from(sum)
+}
+
+#[aoc(day1, part2)]
+pub fn part2(_input: &[String]) -> Answer {
+    Answer::from(0u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = r#"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+"#;
+
+    const PART1_RESULT: u64 = 142;
+    const PART2_RESULT: u64 = 0;
+
+    #[test]
+    fn test_parser() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let ret = vec![
+            "1abc2".to_string(),
+            "pqr3stu8vwx".to_string(),
+            "a1b2c3d4e5f".to_string(),
+            "treb7uchet".to_string(),
+        ];
+
+        assert_eq!(parsed, ret);
+    }
+
+    #[test]
+    fn test_parser_example() {
+        insta::assert_debug_snapshot!(input_generator(TEST_DATA).unwrap());
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(1) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day01", input_generator);
+    }
+}
@@ -0,0 +1,294 @@
+use std::{collections::HashMap, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GameInfo {
+    pub id: u32,
+    pub sets: Vec<CubeSets>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CubeSets(Vec<(u32, String)>);
+
+impl GameInfo {
+    /// Whether every set fits in a bag of 12 red, 13 green and 14 blue cubes.
+    pub fn is_possible(&self) -> bool {
+        const MAX_BLOCKS: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+        let possible = self.sets.iter().all(|set| {
+            let blocks = set.flatten();
+            MAX_BLOCKS
+                .iter()
+                .all(|(color, max)| blocks.get(color).is_none_or(|n| n <= max))
+        });
+        tracing::debug!(game = self.id, possible, "checked game");
+        possible
+    }
+
+    /// Product of the fewest cubes of each colour that make every set possible.
+    pub fn power(&self) -> u64 {
+        let mut min_needed = HashMap::new();
+        for s in &self.sets {
+            for (color, n) in s.flatten() {
+                let needed = min_needed.entry(color).or_insert(0u32);
+                *needed = std::cmp::max(*needed, n);
+            }
+        }
+
+        let power = min_needed
+            .values()
+            .filter(|&&n| n > 0)
+            .try_fold(1u64, |power, &n| power.checked_mul(u64::from(n)))
+            .expect("Game power overflowed");
+        tracing::debug!(game = self.id, power, "game power");
+        power
+    }
+}
+
+impl CubeSets {
+    pub fn flatten(&self) -> HashMap<&str, u32> {
+        let mut blocks = HashMap::new();
+        for s in &self.0 {
+            let total = blocks.entry(s.1.as_str()).or_insert(0u32);
+            *total = total.checked_add(s.0).expect("Cube count overflowed");
+        }
+
+        blocks
+    }
+}
+
+impl FromStr for GameInfo {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, sets) = s.split_once(':').context("Missing ':' after game id")?;
+        let game_id = game
+            .trim()
+            .strip_prefix("Game ")
+            .context("Missing 'Game' prefix")?
+            .trim()
+            .parse::<u32>()
+            .context("Could not parse game id")?;
+        let data = sets
+            .split(';')
+            .map(CubeSets::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(GameInfo {
+            id: game_id,
+            sets: data,
+        })
+    }
+}
+
+impl FromStr for CubeSets {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cubes = s
+            .split(',')
+            .map(|ss| {
+                let (n, c) = ss
+                    .trim()
+                    .split_once(' ')
+                    .context("Missing space between count and colour")?;
+                let n = n
+                    .trim()
+                    .parse::<u32>()
+                    .context("Could not parse cube count")?;
+                let c = c.trim().to_string();
+                Ok((n, c))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(CubeSets(cubes))
+    }
+}
+
+#[aoc_generator(day2)]
+pub fn input_generator(input: &str) -> anyhow::Result<Vec<GameInfo>> {
+    parse::normalise(input)
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(GameInfo::from_str)
+        .collect()
+}
+
+#[aoc(day2, part1)]
+pub fn part1(input: &[GameInfo]) -> Answer {
+    let id_sum = input
+        .iter()
+        .filter(|game_info| game_info.is_possible())
+        .map(|game_info| u64::from(game_info.id))
+        .try_fold(0u64, |sum, id| sum.checked_add(id))
+        .expect("Sum of game ids overflowed");
+
+    Answer::from(id_sum)
+}
+
+#[aoc(day2, part2)]
+pub fn part2(input: &[GameInfo]) -> Answer {
+    let power_sum = input
+        .iter()
+        .map(GameInfo::power)
+        .try_fold(0u64, |sum, power| sum.checked_add(power))
+        .expect("Sum of powers overflowed");
+
+    Answer::from(power_sum)
+}
+
+/// `part1` reading one game at a time, in constant memory.
+pub fn part1_stream<R: BufRead>(reader: R) -> anyhow::Result<Answer> {
+    let mut id_sum = 0u64;
+    parse::for_each_line(reader, |line| {
+        let game_info = GameInfo::from_str(line)?;
+        if game_info.is_possible() {
+            id_sum = id_sum
+                .checked_add(u64::from(game_info.id))
+                .context("Sum of game ids overflowed")?;
+        }
+        Ok(())
+    })?;
+
+    Ok(Answer::from(id_sum))
+}
+
+/// `part2` reading one game at a time, in constant memory.
+pub fn part2_stream<R: BufRead>(reader: R) -> anyhow::Result<Answer> {
+    let mut power_sum = 0u64;
+    parse::for_each_line(reader, |line| {
+        let game_info = GameInfo::from_str(line)?;
+        power_sum = power_sum
+            .checked_add(game_info.power())
+            .context("Sum of powers overflowed")?;
+        Ok(())
+    })?;
+
+    Ok(Answer::from(power_sum))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::random::{self, GamesConfig};
+    use aoc_common::differential::find_divergence;
+
+    const TEST_DATA: &str = r#"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#;
+
+    const PART1_RESULT: u64 = 8;
+    const PART2_RESULT: u64 = 2286;
+
+    #[test]
+    fn test_parser() {
+        const TEST_PARSE_DATA: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let parsed = input_generator(TEST_PARSE_DATA).unwrap();
+        let ret = vec![GameInfo {
+            id: 1,
+            sets: vec![
+                CubeSets(vec![(3, "blue".to_string()), (4, "red".to_string())]),
+                CubeSets(vec![
+                    (1, "red".to_string()),
+                    (2, "green".to_string()),
+                    (6, "blue".to_string()),
+                ]),
+                CubeSets(vec![(2, "green".to_string())]),
+            ],
+        }];
+
+        assert_eq!(parsed, ret);
+    }
+
+    #[test]
+    fn test_parser_example() {
+        insta::assert_debug_snapshot!(input_generator(TEST_DATA).unwrap());
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(2) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_power_past_u32() {
+        let parsed = input_generator("Game 1: 2000 red, 2000 green, 2000 blue").unwrap();
+
+        assert_eq!(parsed[0].power(), 8_000_000_000);
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(part1_stream(TEST_DATA.as_bytes()).unwrap(), PART1_RESULT);
+        assert_eq!(part2_stream(TEST_DATA.as_bytes()).unwrap(), PART2_RESULT);
+
+        let crlf = TEST_DATA.replace('\n', "\r\n");
+        assert_eq!(part1_stream(crlf.as_bytes()).unwrap(), PART1_RESULT);
+        assert!(part1_stream("not a line\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_stream_differential() {
+        let config = |scale: usize| GamesConfig {
+            games: 20 * scale,
+            ..Default::default()
+        };
+        let divergence = find_divergence(
+            1..6,
+            0..10,
+            |rng, scale| random::day02(rng, &config(scale)),
+            |input| {
+                input_generator(input)
+                    .ok()
+                    .map(|parsed| (part1(&parsed), part2(&parsed)))
+            },
+            |input| {
+                part1_stream(input.as_bytes())
+                    .ok()
+                    .zip(part2_stream(input.as_bytes()).ok())
+            },
+        );
+
+        assert!(divergence.is_none(), "{}", divergence.unwrap());
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day02", input_generator);
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+use aoc_common::{
+    answer::Answer,
+    grid::{Colour, Glyph, Grid, Pos, Span},
+};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Data;
+type OutputData = Answer;
+
+#[aoc_generator(day3)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    let grid = Grid::parse(input, Cell::try_from)?;
+    let numbers = grid
+        .spans(|c| matches!(c, Cell::Digit(_)))
+        .into_iter()
+        .map(|span| {
+            let value = span
+                .positions()
+                .try_fold(0u64, |value, pos| match grid[pos] {
+                    Cell::Digit(d) => value.checked_mul(10)?.checked_add(u64::from(d)),
+                    _ => None,
+                });
+            let value = value.with_context(|| format!("Number at {:?} is too large", span))?;
+            Ok(PartNumber { span, value })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Data { grid, numbers })
+}
+
+#[aoc(day3, part1)]
+pub fn part1(input: &ChallangeData) -> OutputData {
+    let sum = input
+        .numbers
+        .iter()
+        .filter(|n| {
+            input
+                .grid
+                .around_span(&n.span)
+                .any(|p| matches!(input.grid[p], Cell::Symbol(_)))
+        })
+        .try_fold(0u64, |sum, n| sum.checked_add(n.value))
+        .expect("Sum of part numbers overflowed");
+
+    Answer::from(sum)
+}
+
+#[aoc(day3, part2)]
+pub fn part2(input: &ChallangeData) -> OutputData {
+    let mut gears = HashMap::<Pos, Vec<u64>>::new();
+    for n in &input.numbers {
+        for pos in input.grid.around_span(&n.span) {
+            if input.grid[pos] == Cell::Symbol('*') {
+                gears.entry(pos).or_default().push(n.value);
+            }
+        }
+    }
+
+    let sum = gears
+        .iter()
+        .filter_map(|(pos, numbers)| match numbers[..] {
+            [a, b] => {
+                let ratio = a.checked_mul(b).expect("Gear ratio overflowed");
+                tracing::debug!(x = pos.x, y = pos.y, a, b, ratio, "gear");
+                Some(ratio)
+            }
+            _ => None,
+        })
+        .try_fold(0u64, |sum, ratio| sum.checked_add(ratio))
+        .expect("Sum of gear ratios overflowed");
+
+    Answer::from(sum)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = r#"
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+"#;
+
+    const PART1_RESULT: u64 = 4361;
+    const PART2_RESULT: u64 = 467835;
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(3) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_numbers_at_edges() {
+        // Numbers touching the right edge, and symbols only diagonally.
+        let parsed = input_generator("12*.\n..34\n#..5").unwrap();
+
+        assert_eq!(
+            parsed.numbers.iter().map(|n| n.value).collect::<Vec<_>>(),
+            vec![12, 34, 5]
+        );
+        assert_eq!(part1(&parsed), 46u64);
+        assert_eq!(part2(&parsed), 12u64 * 34);
+    }
+
+    #[test]
+    fn test_number_overflow() {
+        assert!(input_generator("123456789012345678901234567890*").is_err());
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day03", input_generator);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub grid: Grid<Cell>,
+    pub numbers: Vec<PartNumber>,
+}
+
+/// A number in the schematic and the cells its digits cover.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PartNumber {
+    pub span: Span,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Digit(u8),
+    Symbol(char),
+}
+
+impl TryFrom<char> for Cell {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Cell::Empty,
+            '0'..='9' => Cell::Digit(u8::try_from(c)? - b'0'),
+            _ if c.is_ascii_graphic() => Cell::Symbol(c),
+            _ => bail!("Unknown cell {:?}", c),
+        })
+    }
+}
+
+impl Glyph for Cell {
+    fn glyph(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Digit(d) => char::from(b'0' + d),
+            Cell::Symbol(c) => *c,
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            Cell::Empty => Colour::Grey,
+            Cell::Digit(_) => Colour::Default,
+            Cell::Symbol(_) => Colour::Red,
+        }
+    }
+}
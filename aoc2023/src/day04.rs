@@ -0,0 +1,276 @@
+use std::{collections::VecDeque, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CardInfo {
+    pub card_num: u32,
+    pub winning_nums: Vec<u32>,
+    pub user_nums: Vec<u32>,
+}
+
+impl CardInfo {
+    pub fn get_matches(&self) -> Vec<u32> {
+        self.user_nums
+            .iter()
+            .filter(|&n| self.is_number_winner(*n))
+            .copied()
+            .collect()
+    }
+
+    pub fn points(&self) -> u64 {
+        let matches = u32::try_from(self.get_matches().len()).expect("Too many matches");
+        let points = if matches > 0 {
+            2u64.checked_pow(matches - 1)
+                .expect("Card points overflowed")
+        } else {
+            0
+        };
+        tracing::debug!(card = self.card_num, matches, points, "scored card");
+        points
+    }
+
+    fn is_number_winner(&self, num: u32) -> bool {
+        for n in &self.winning_nums {
+            if *n == num {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl FromStr for CardInfo {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (card, nums) = s
+            .trim()
+            .split_once(':')
+            .context("Missing ':' after card number")?;
+        let card = card
+            .trim()
+            .split(' ')
+            .next_back()
+            .context("Missing card number")?
+            .trim()
+            .parse::<u32>()
+            .context("Could not parse card number")?;
+        let (winning, user) = nums
+            .trim()
+            .split_once('|')
+            .context("Missing '|' separator")?;
+        let winning = parse::numbers(winning).context("Could not parse winning number")?;
+        let user = parse::numbers(user).context("Could not parse user's number")?;
+
+        Ok(CardInfo {
+            card_num: card,
+            winning_nums: winning,
+            user_nums: user,
+        })
+    }
+}
+
+#[aoc_generator(day4)]
+pub fn input_generator(input: &str) -> anyhow::Result<Vec<CardInfo>> {
+    parse::normalise(input)
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(CardInfo::from_str)
+        .collect()
+}
+
+#[aoc(day4, part1)]
+pub fn part1(input: &[CardInfo]) -> Answer {
+    let points = input
+        .iter()
+        .map(CardInfo::points)
+        .try_fold(0u64, |sum, p| sum.checked_add(p))
+        .expect("Sum of points overflowed");
+
+    Answer::from(points)
+}
+
+#[aoc(day4, part2)]
+pub fn part2(input: &[CardInfo]) -> Answer {
+    let mut groups = vec![0u64; input.len()];
+    for i in 0..input.len() {
+        groups[i] += 1;
+        let matches = input[i].get_matches().len();
+        tracing::debug!(
+            card = input[i].card_num,
+            copies = groups[i],
+            matches,
+            "won copies"
+        );
+        for j in 0..matches {
+            let t = i + j + 1;
+            if t < groups.len() {
+                groups[t] = groups[t]
+                    .checked_add(groups[i])
+                    .expect("Card copies overflowed");
+            }
+        }
+    }
+
+    let cards = groups
+        .iter()
+        .try_fold(0u64, |sum, g| sum.checked_add(*g))
+        .expect("Total cards overflowed");
+
+    Answer::from(cards)
+}
+
+/// `part1` reading one card at a time, in constant memory.
+pub fn part1_stream<R: BufRead>(reader: R) -> anyhow::Result<Answer> {
+    let mut points = 0u64;
+    parse::for_each_line(reader, |line| {
+        let card = CardInfo::from_str(line)?;
+        points = points
+            .checked_add(card.points())
+            .context("Sum of points overflowed")?;
+        Ok(())
+    })?;
+
+    Ok(Answer::from(points))
+}
+
+/// `part2` reading one card at a time. Only the copies won for the next few
+/// cards are kept, at most one per number on a card.
+pub fn part2_stream<R: BufRead>(reader: R) -> anyhow::Result<Answer> {
+    let mut pending = VecDeque::<u64>::new();
+    let mut total = 0u64;
+    parse::for_each_line(reader, |line| {
+        let card = CardInfo::from_str(line)?;
+        let copies = pending
+            .pop_front()
+            .unwrap_or(0)
+            .checked_add(1)
+            .context("Card copies overflowed")?;
+        total = total
+            .checked_add(copies)
+            .context("Total of cards overflowed")?;
+
+        let matches = card.get_matches().len();
+        if pending.len() < matches {
+            pending.resize(matches, 0);
+        }
+        for won in pending.iter_mut().take(matches) {
+            *won = won.checked_add(copies).context("Card copies overflowed")?;
+        }
+        Ok(())
+    })?;
+
+    Ok(Answer::from(total))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::random::{self, CardsConfig};
+    use aoc_common::differential::find_divergence;
+
+    const TEST_DATA: &str = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+"#;
+
+    const PART1_RESULT: u64 = 13;
+    const PART2_RESULT: u64 = 30;
+
+    #[test]
+    fn test_parser() {
+        let parsed = input_generator("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        let ret = vec![CardInfo {
+            card_num: 1,
+            winning_nums: vec![41, 48, 83, 86, 17],
+            user_nums: vec![83, 86, 6, 31, 17, 9, 48, 53],
+        }];
+
+        assert_eq!(parsed, ret);
+    }
+
+    #[test]
+    fn test_parser_example() {
+        insta::assert_debug_snapshot!(input_generator(TEST_DATA).unwrap());
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(4) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(part1_stream(TEST_DATA.as_bytes()).unwrap(), PART1_RESULT);
+        assert_eq!(part2_stream(TEST_DATA.as_bytes()).unwrap(), PART2_RESULT);
+
+        let crlf = TEST_DATA.replace('\n', "\r\n");
+        assert_eq!(part1_stream(crlf.as_bytes()).unwrap(), PART1_RESULT);
+        assert!(part1_stream("not a line\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_stream_differential() {
+        let config = |scale: usize| CardsConfig {
+            cards: 20 * scale,
+            ..Default::default()
+        };
+        let divergence = find_divergence(
+            1..6,
+            0..10,
+            |rng, scale| random::day04(rng, &config(scale)),
+            |input| {
+                input_generator(input)
+                    .ok()
+                    .map(|parsed| (part1(&parsed), part2(&parsed)))
+            },
+            |input| {
+                part1_stream(input.as_bytes())
+                    .ok()
+                    .zip(part2_stream(input.as_bytes()).ok())
+            },
+        );
+
+        assert!(divergence.is_none(), "{}", divergence.unwrap());
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day04", input_generator);
+    }
+}
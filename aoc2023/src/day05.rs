@@ -0,0 +1,423 @@
+use std::{ops::Range, str::FromStr};
+
+use anyhow::{bail, Context};
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+#[aoc_generator(day5)]
+pub fn input_generator(input: &str) -> anyhow::Result<Data> {
+    let data = Data::from_str(&parse::normalise(input))?;
+    tracing::debug!(
+        seeds = data.seeds.len(),
+        mappings = data.seed_to_soil.0.len()
+            + data.soil_to_fertilizer.0.len()
+            + data.fertilizer_to_water.0.len()
+            + data.water_to_light.0.len()
+            + data.light_to_temperature.0.len()
+            + data.temperature_to_humidity.0.len()
+            + data.humidity_to_location.0.len(),
+        "parsed almanac"
+    );
+    Ok(data)
+}
+
+#[aoc(day5, part1)]
+pub fn part1(input: &Data) -> Answer {
+    let location = input
+        .seeds
+        .iter()
+        .map(|s| input.map_seed_to_location(*s))
+        .min()
+        .expect("Could not get min location");
+
+    Answer::from(location)
+}
+
+#[aoc(day5, part2)]
+pub fn part2(input: &Data) -> Answer {
+    let seeds = input
+        .seeds
+        .chunks(2)
+        .map(|p| p[0] as u64..p[0] as u64 + p[1] as u64)
+        .filter(|r| !r.is_empty())
+        .collect::<Vec<_>>();
+    let location = input
+        .map_seed_ranges_to_locations(seeds)
+        .iter()
+        .map(|r| r.start)
+        .min()
+        .expect("Could not get min location");
+
+    Answer::from(location)
+}
+
+/// Reference implementation of `part2`, mapping every seed one by one.
+#[aoc(day5, part2, naive)]
+pub fn part2_naive(input: &Data) -> Answer {
+    let location = input
+        .seeds
+        .chunks(2)
+        .flat_map(|p| {
+            let start = &p[0];
+            let len = p[1];
+            (0..len).map(|i| {
+                let seed = i + *start;
+                input.map_seed_to_location(seed)
+            })
+        })
+        .min()
+        .expect("Could not get min location");
+
+    Answer::from(location)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::random::{self, AlmanacConfig};
+    use aoc_common::differential::find_divergence;
+
+    const TEST_DATA: &str = r#"
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#;
+
+    const PART1_RESULT: u64 = 35;
+    const PART2_RESULT: u64 = 46;
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(5) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_part2_naive() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2_naive(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_mapping_at_end_of_range() {
+        let mapping = Mapping::new(0, u32::MAX - 1, 2);
+
+        assert!(mapping.is_mapping_valid(u32::MAX));
+        assert_eq!(mapping.map(u32::MAX), 1);
+    }
+
+    #[test]
+    fn test_part2_differential() {
+        let config = |scale: usize| AlmanacConfig {
+            seed_ranges: scale,
+            max_seed_range_len: 50 * scale as u32,
+            mappings_per_map: 2 * scale,
+            max_value: 5000,
+        };
+        let divergence = find_divergence(
+            1..8,
+            0..20,
+            |rng, scale| random::day05(rng, &config(scale)),
+            |input| input_generator(input).ok().map(|d| part2_naive(&d)),
+            |input| input_generator(input).ok().map(|d| part2(&d)),
+        );
+
+        assert!(divergence.is_none(), "{}", divergence.unwrap());
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day05", input_generator);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub seeds: Vec<u32>,
+    pub soil_to_fertilizer: DataMap,
+    pub seed_to_soil: DataMap,
+    pub fertilizer_to_water: DataMap,
+    pub water_to_light: DataMap,
+    pub light_to_temperature: DataMap,
+    pub temperature_to_humidity: DataMap,
+    pub humidity_to_location: DataMap,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DataMap(Vec<Mapping>);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mapping {
+    pub destination_range_start: u32,
+    pub source_range_start: u32,
+    pub range_len: u32,
+}
+
+impl FromStr for Data {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.trim().split("\n\n").collect::<Vec<_>>();
+        if split.len() != 8 {
+            bail!("Expected seeds and 7 maps, found {} sections", split.len());
+        }
+
+        let seeds = split[0]
+            .split(':')
+            .nth(1)
+            .context("Missing ':' after seeds")?;
+        let seeds = parse::numbers(seeds).context("Could not parse seed number")?;
+
+        let seed_to_soil =
+            DataMap::from_str(split[1]).context("Could not parse DataMap for seed_to_soil")?;
+        let soil_to_fertilizer = DataMap::from_str(split[2])
+            .context("Could not parse DataMap for soil_to_fertilizer")?;
+        let fertilizer_to_water = DataMap::from_str(split[3])
+            .context("Could not parse DataMap for fertilizer_to_water")?;
+        let water_to_light =
+            DataMap::from_str(split[4]).context("Could not parse DataMap for water_to_light")?;
+        let light_to_temperature = DataMap::from_str(split[5])
+            .context("Could not parse DataMap for light_to_temperature")?;
+        let temperature_to_humidity = DataMap::from_str(split[6])
+            .context("Could not parse DataMap for temperature_to_humidity")?;
+        let humidity_to_location = DataMap::from_str(split[7])
+            .context("Could not parse DataMap for humidity_to_location")?;
+
+        Ok(Self {
+            seeds,
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        })
+    }
+}
+
+impl FromStr for DataMap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mappings = s
+            .trim()
+            .split('\n')
+            .skip(1)
+            .map(|line| Mapping::from_str(line).context("Could not parse mapping"))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self(mappings))
+    }
+}
+
+impl FromStr for Mapping {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.trim().split(' ').collect::<Vec<_>>();
+        if split.len() != 3 {
+            bail!("Expected 3 numbers in mapping, found {}", split.len());
+        }
+
+        let a = split[0]
+            .trim()
+            .parse::<u32>()
+            .context("Could not parse destination_range_start")?;
+        let b = split[1]
+            .trim()
+            .parse::<u32>()
+            .context("Could not parse source_range_start")?;
+        let c = split[2]
+            .trim()
+            .parse::<u32>()
+            .context("Could not parse range_leng")?;
+
+        Ok(Self {
+            destination_range_start: a,
+            source_range_start: b,
+            range_len: c,
+        })
+    }
+}
+
+impl Mapping {
+    pub fn new(destination_range_start: u32, source_range_start: u32, range_len: u32) -> Self {
+        Self {
+            destination_range_start,
+            source_range_start,
+            range_len,
+        }
+    }
+
+    pub fn map(&self, val: u32) -> u32 {
+        let diff = val - self.source_range_start;
+        self.destination_range_start + diff
+    }
+
+    pub fn is_mapping_valid(&self, val: u32) -> bool {
+        // Widened, the end of the last range can be past u32::MAX.
+        val >= self.source_range_start
+            && u64::from(val) < u64::from(self.source_range_start) + u64::from(self.range_len)
+    }
+}
+
+impl DataMap {
+    pub fn map(&self, val: u32) -> u32 {
+        match self.get_mapping(val) {
+            None => val,
+            Some(mapping) => mapping.map(val),
+        }
+    }
+
+    fn get_mapping(&self, val: u32) -> Option<&Mapping> {
+        self.0.iter().find(|m| m.is_mapping_valid(val))
+    }
+
+    /// Maps a whole range of values at once, splitting it wherever it crosses
+    /// the edge of a mapping. Ranges are `u64` so their ends cannot overflow.
+    pub fn map_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut pending = vec![range];
+        let mut mapped = Vec::new();
+        for m in &self.0 {
+            let src = m.source_range_start as u64;
+            let src_end = src + m.range_len as u64;
+            let dst = m.destination_range_start as u64;
+
+            let mut rest = Vec::new();
+            for r in pending {
+                let start = r.start.max(src);
+                let end = r.end.min(src_end);
+                if start >= end {
+                    rest.push(r);
+                    continue;
+                }
+
+                mapped.push(start - src + dst..end - src + dst);
+                if r.start < start {
+                    rest.push(r.start..start);
+                }
+                if end < r.end {
+                    rest.push(end..r.end);
+                }
+            }
+            pending = rest;
+        }
+
+        mapped.extend(pending);
+        mapped
+    }
+}
+
+impl Data {
+    pub fn map_seed_to_location(&self, seed: u32) -> u32 {
+        let soil = self.seed_to_soil.map(seed);
+        let fertilizer = self.soil_to_fertilizer.map(soil);
+        let water = self.fertilizer_to_water.map(fertilizer);
+        let light = self.water_to_light.map(water);
+        let temperature = self.light_to_temperature.map(light);
+        let humidity = self.temperature_to_humidity.map(temperature);
+        let location = self.humidity_to_location.map(humidity);
+        tracing::trace!(
+            seed,
+            soil,
+            fertilizer,
+            water,
+            light,
+            temperature,
+            humidity,
+            location,
+            "mapped seed"
+        );
+        location
+    }
+
+    pub fn map_seed_ranges_to_locations(&self, seeds: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        [
+            ("soil", &self.seed_to_soil),
+            ("fertilizer", &self.soil_to_fertilizer),
+            ("water", &self.fertilizer_to_water),
+            ("light", &self.water_to_light),
+            ("temperature", &self.light_to_temperature),
+            ("humidity", &self.temperature_to_humidity),
+            ("location", &self.humidity_to_location),
+        ]
+        .iter()
+        .fold(seeds, |ranges, (stage, map)| {
+            let _span = tracing::debug_span!("stage", stage).entered();
+            let mapped = ranges
+                .into_iter()
+                .flat_map(|r| map.map_range(r))
+                .collect::<Vec<_>>();
+            tracing::debug!(ranges = mapped.len(), "mapped ranges");
+            tracing::trace!(?mapped);
+            mapped
+        })
+    }
+}
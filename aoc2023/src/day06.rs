@@ -0,0 +1,269 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Data;
+type OutputData = Answer;
+
+#[aoc_generator(day6)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    ChallangeData::from_str(&parse::normalise(input))
+}
+
+#[aoc(day6, part1)]
+pub fn part1(input: &ChallangeData) -> OutputData {
+    Answer::from(product(input.0.iter().map(|rd| rd.count_wins())))
+}
+
+#[aoc(day6, part2)]
+pub fn part2(input: &ChallangeData) -> OutputData {
+    Answer::from(input.merge_samples().count_wins())
+}
+
+#[aoc(day6, part1, naive)]
+pub fn part1_naive(input: &ChallangeData) -> OutputData {
+    Answer::from(product(input.0.iter().map(|rd| {
+        u64::try_from(rd.get_hold_time_win_possibilities().len()).unwrap()
+    })))
+}
+
+#[aoc(day6, part2, naive)]
+pub fn part2_naive(input: &ChallangeData) -> OutputData {
+    Answer::from(
+        input
+            .merge_samples()
+            .get_hold_time_win_possibilities()
+            .len(),
+    )
+}
+
+/// Product of the win counts, failing loudly on overflow.
+fn product(counts: impl Iterator<Item = u64>) -> u64 {
+    counts.fold(1, |acc, c| {
+        acc.checked_mul(c)
+            .expect("Product of win counts overflowed")
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::random::{self, RacesConfig};
+    use aoc_common::differential::find_divergence;
+
+    const TEST_DATA: &str = r#"
+Time:      7  15   30
+Distance:  9  40  200
+"#;
+
+    const PART1_RESULT: u64 = 288;
+    const PART2_RESULT: u64 = 71503;
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(6) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_part1_naive() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1_naive(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2_naive() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2_naive(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_count_wins_differential() {
+        let config = |scale: usize| RacesConfig {
+            races: scale,
+            max_time: 10u64.pow(scale as u32),
+        };
+        let divergence = find_divergence(
+            1..6,
+            0..20,
+            |rng, scale| random::day06(rng, &config(scale)),
+            |input| {
+                input_generator(input).ok().map(|d| {
+                    d.0.iter()
+                        .map(|r| r.get_hold_time_win_possibilities().len() as u64)
+                        .collect::<Vec<_>>()
+                })
+            },
+            |input| {
+                input_generator(input)
+                    .ok()
+                    .map(|d| d.0.iter().map(|r| r.count_wins()).collect::<Vec<_>>())
+            },
+        );
+
+        assert!(divergence.is_none(), "{}", divergence.unwrap());
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day06", input_generator);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data(Vec<RaceData>);
+
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct RaceData {
+    pub time: u64,
+    pub dst: u64,
+}
+
+impl FromStr for Data {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s
+            .trim()
+            .split('\n')
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>();
+        if split.len() != 2 {
+            bail!(
+                "Expected Time and Distance lines, found {} lines",
+                split.len()
+            );
+        }
+
+        let times = split[0]
+            .split(':')
+            .nth(1)
+            .context("Missing ':' after Time")?;
+        let times = parse::numbers::<u64>(times).context("Could not parse time number")?;
+        let distances = split[1]
+            .split(':')
+            .nth(1)
+            .context("Missing ':' after Distance")?;
+        let distances =
+            parse::numbers::<u64>(distances).context("Could not parse distance number")?;
+
+        if times.len() != distances.len() {
+            bail!(
+                "Found {} times but {} distances",
+                times.len(),
+                distances.len()
+            );
+        }
+
+        let samples = times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, dst)| RaceData { time, dst })
+            .collect::<Vec<_>>();
+
+        Ok(Self(samples))
+    }
+}
+
+impl RaceData {
+    /// Number of hold times that beat the record, from the roots of
+    /// `hold * (time - hold) = dst`. The integer square root is only a first
+    /// guess, the bound is then corrected exactly.
+    pub fn count_wins(&self) -> u64 {
+        let (time, dst) = (self.time as u128, self.dst as u128);
+        let beats = |hold: u128| hold * (time - hold) > dst;
+        if !beats(time / 2) {
+            return 0;
+        }
+
+        let mut lo = (time - (time * time - 4 * dst).isqrt()) / 2;
+        while !beats(lo) {
+            lo += 1;
+        }
+        while lo > 0 && beats(lo - 1) {
+            lo -= 1;
+        }
+
+        let wins = u64::try_from(time - 2 * lo + 1).expect("Win count does not fit in u64");
+        tracing::debug!(
+            time = self.time,
+            dst = self.dst,
+            first_win = %lo,
+            wins,
+            "counted wins"
+        );
+        wins
+    }
+
+    /// Reference implementation of `count_wins`, trying every hold time.
+    pub fn get_hold_time_win_possibilities(&self) -> Vec<u64> {
+        (0..=self.time)
+            .filter_map(|i| {
+                let hold = i;
+                let run = self.time - hold;
+                let distance = hold * run;
+                match distance > self.dst {
+                    true => Some(hold),
+                    false => None,
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+impl Data {
+    pub fn merge_samples(&self) -> RaceData {
+        let mut time = String::new();
+        let mut dst = String::new();
+        self.0.iter().for_each(|s| {
+            time += &s.time.to_string();
+            dst += &s.dst.to_string();
+        });
+
+        let time = time.parse::<u64>().expect("Could not parse merged time");
+        let dst = dst.parse::<u64>().expect("Could not parse merged dst");
+
+        tracing::debug!(time, dst, "merged races");
+        RaceData { time, dst }
+    }
+}
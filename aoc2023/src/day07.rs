@@ -0,0 +1,267 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Vec<Hand>;
+type OutputData = Answer;
+
+#[aoc_generator(day7)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    parse::normalise(input)
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(Hand::from_str)
+        .collect()
+}
+
+#[aoc(day7, part1)]
+pub fn part1(input: &[Hand]) -> OutputData {
+    Answer::from(total_winnings(input, &Rules::STANDARD))
+}
+
+#[aoc(day7, part2)]
+pub fn part2(input: &[Hand]) -> OutputData {
+    Answer::from(total_winnings(input, &Rules::JOKERS))
+}
+
+/// Sum of every hand's bid times its rank, the weakest hand ranking 1.
+pub fn total_winnings(hands: &[Hand], rules: &Rules) -> u64 {
+    let mut ranked = hands
+        .iter()
+        .map(|hand| (rules.key(hand), hand.bid))
+        .collect::<Vec<_>>();
+    ranked.sort_unstable();
+
+    ranked
+        .iter()
+        .zip(1u64..)
+        .map(|((key, bid), rank)| {
+            tracing::debug!(?key, bid, rank, "ranked hand");
+            bid.checked_mul(rank).expect("Winnings overflowed")
+        })
+        .try_fold(0u64, |sum, w| sum.checked_add(w))
+        .expect("Total winnings overflowed")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = r#"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+"#;
+
+    const PART1_RESULT: u64 = 6440;
+    const PART2_RESULT: u64 = 5905;
+
+    #[test]
+    fn test_parser() {
+        let parsed = input_generator("32T3K 765").unwrap();
+        let ret = vec![Hand {
+            cards: ['3', '2', 'T', '3', 'K'],
+            bid: 765,
+        }];
+
+        assert_eq!(parsed, ret);
+    }
+
+    #[test]
+    fn test_parser_example() {
+        insta::assert_debug_snapshot!(input_generator(TEST_DATA).unwrap());
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(7) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_hand_types() {
+        let hand_type = |cards: &str, rules: &Rules| {
+            let hand = Hand::from_str(&format!("{} 1", cards)).unwrap();
+            rules.hand_type(&hand)
+        };
+
+        assert_eq!(hand_type("AAAAA", &Rules::STANDARD), HandType::FiveOfAKind);
+        assert_eq!(hand_type("AA8AA", &Rules::STANDARD), HandType::FourOfAKind);
+        assert_eq!(hand_type("23332", &Rules::STANDARD), HandType::FullHouse);
+        assert_eq!(hand_type("TTT98", &Rules::STANDARD), HandType::ThreeOfAKind);
+        assert_eq!(hand_type("23432", &Rules::STANDARD), HandType::TwoPair);
+        assert_eq!(hand_type("A23A4", &Rules::STANDARD), HandType::OnePair);
+        assert_eq!(hand_type("23456", &Rules::STANDARD), HandType::HighCard);
+
+        assert_eq!(hand_type("QJJQ2", &Rules::STANDARD), HandType::TwoPair);
+        assert_eq!(hand_type("QJJQ2", &Rules::JOKERS), HandType::FourOfAKind);
+        assert_eq!(hand_type("JJJJJ", &Rules::JOKERS), HandType::FiveOfAKind);
+        assert_eq!(hand_type("2345J", &Rules::JOKERS), HandType::OnePair);
+    }
+
+    #[test]
+    fn test_card_order() {
+        let key = |cards: &str, rules: &Rules| {
+            rules.key(&Hand::from_str(&format!("{} 1", cards)).unwrap())
+        };
+
+        // Same type, first card decides.
+        assert!(key("33332", &Rules::STANDARD) > key("2AAAA", &Rules::STANDARD));
+        // A joker is the weakest card on its own, whatever it stands for.
+        assert!(key("JKKK2", &Rules::JOKERS) < key("QQQQ2", &Rules::JOKERS));
+        assert!(key("JKKK2", &Rules::STANDARD) < key("QQQQ2", &Rules::STANDARD));
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        // Aces low, twos wild.
+        let rules = Rules {
+            order: "2A3456789TJQK",
+            wildcard: Some('2'),
+        };
+        let hands = input_generator("A2A2K 10\nKKKQA 1\n").unwrap();
+
+        assert_eq!(rules.hand_type(&hands[0]), HandType::FourOfAKind);
+        assert_eq!(total_winnings(&hands, &rules), 21);
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day07", input_generator);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: [char; 5],
+    pub bid: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// How hands are compared: the strength of each card, weakest first, and the
+/// card, if any, that stands for whichever card makes the best hand.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    pub order: &'static str,
+    pub wildcard: Option<char>,
+}
+
+impl Rules {
+    pub const STANDARD: Rules = Rules {
+        order: "23456789TJQKA",
+        wildcard: None,
+    };
+
+    /// Part 2: jacks are jokers, wild and the weakest card.
+    pub const JOKERS: Rules = Rules {
+        order: "J23456789TQKA",
+        wildcard: Some('J'),
+    };
+
+    pub fn strength(&self, card: char) -> usize {
+        self.order
+            .chars()
+            .position(|c| c == card)
+            .unwrap_or_else(|| panic!("Card {:?} is not in the order {}", card, self.order))
+    }
+
+    pub fn hand_type(&self, hand: &Hand) -> HandType {
+        let mut counts = Vec::<(char, usize)>::new();
+        let mut wild = 0;
+        for &card in &hand.cards {
+            if Some(card) == self.wildcard {
+                wild += 1;
+                continue;
+            }
+            match counts.iter_mut().find(|(c, _)| *c == card) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((card, 1)),
+            }
+        }
+
+        // The wildcards always do best joining the largest group.
+        let mut counts = counts.into_iter().map(|(_, n)| n).collect::<Vec<_>>();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts.first_mut() {
+            Some(largest) => *largest += wild,
+            None => counts.push(wild),
+        }
+
+        match counts[..] {
+            [5] => HandType::FiveOfAKind,
+            [4, 1] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, 1, 1] => HandType::ThreeOfAKind,
+            [2, 2, 1] => HandType::TwoPair,
+            [2, 1, 1, 1] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// Sort key of a hand: its type, then the strength of each card in order.
+    pub fn key(&self, hand: &Hand) -> (HandType, [usize; 5]) {
+        (self.hand_type(hand), hand.cards.map(|c| self.strength(c)))
+    }
+}
+
+impl FromStr for Hand {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cards, bid) = s
+            .trim()
+            .split_once(' ')
+            .context("Missing space between cards and bid")?;
+        let cards = cards.chars().collect::<Vec<_>>();
+        let cards: [char; 5] = cards
+            .try_into()
+            .map_err(|c: Vec<char>| anyhow::anyhow!("Expected 5 cards, found {}", c.len()))?;
+        if let Some(card) = cards.iter().find(|c| !Rules::STANDARD.order.contains(**c)) {
+            bail!("Unknown card {:?}", card);
+        }
+        let bid = bid.trim().parse::<u64>().context("Could not parse bid")?;
+
+        Ok(Hand { cards, bid })
+    }
+}
@@ -0,0 +1,434 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+use aoc_common::{answer::Answer, math, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Data;
+type OutputData = Answer;
+
+#[aoc_generator(day8)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    let input = parse::normalise(input);
+    let (instructions, network) = input
+        .split_once("\n\n")
+        .context("Missing blank line after the instructions")?;
+
+    let instructions = instructions
+        .chars()
+        .map(Turn::try_from)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if instructions.is_empty() {
+        bail!("No instructions");
+    }
+
+    let lines = network
+        .lines()
+        .map(|line| {
+            let (name, targets) = line.split_once('=').context("Missing '='")?;
+            let (left, right) = targets
+                .trim()
+                .strip_prefix('(')
+                .and_then(|t| t.strip_suffix(')'))
+                .and_then(|t| t.split_once(','))
+                .with_context(|| format!("Invalid targets {:?}", targets))?;
+            Ok((name.trim(), left.trim(), right.trim()))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut index = HashMap::new();
+    for (i, (name, _, _)) in lines.iter().enumerate() {
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+            bail!("Invalid node name {:?}", name);
+        }
+        if index.insert(*name, i).is_some() {
+            bail!("Node {} defined twice", name);
+        }
+    }
+    let lookup = |name: &str| {
+        index
+            .get(name)
+            .copied()
+            .with_context(|| format!("Unknown node {}", name))
+    };
+    let nodes = lines
+        .iter()
+        .map(|(name, left, right)| {
+            Ok(Node {
+                name: name.to_string(),
+                left: lookup(left)?,
+                right: lookup(right)?,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Data {
+        instructions,
+        nodes,
+    })
+}
+
+#[aoc(day8, part1)]
+pub fn part1(input: &ChallangeData) -> OutputData {
+    let start = input.find("AAA").expect("No node AAA");
+    let orbit = input.orbit(start, |node| node.name == "ZZZ");
+    let steps = earliest_common(&[orbit]).expect("ZZZ is never reached");
+
+    Answer::from(steps)
+}
+
+#[aoc(day8, part2)]
+pub fn part2(input: &ChallangeData) -> OutputData {
+    let orbits = input
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.name.ends_with('A'))
+        .map(|(start, _)| input.orbit(start, |node| node.name.ends_with('Z')))
+        .collect::<Vec<_>>();
+    let steps = earliest_common(&orbits).expect("The ghosts never all reach a Z node together");
+
+    Answer::from(steps)
+}
+
+/// The first step count at which every orbit is on an end node at once.
+///
+/// Below the latest loop start, only the end nodes before that orbit's loop
+/// are candidates. Past it every orbit is periodic, and each choice of one
+/// end node per loop is a system of congruences solved with the CRT.
+pub fn earliest_common(orbits: &[Orbit]) -> Option<u64> {
+    let latest = orbits.iter().max_by_key(|o| o.start)?;
+    if let Some(&t) = latest
+        .prefix
+        .iter()
+        .find(|&&t| orbits.iter().all(|o| o.contains(t)))
+    {
+        return Some(t);
+    }
+
+    let mut congruences = vec![(0, 1)];
+    for orbit in orbits {
+        congruences = congruences
+            .iter()
+            .flat_map(|&c| {
+                orbit
+                    .looping
+                    .iter()
+                    .filter_map(move |&t| math::crt(c, (t % orbit.period, orbit.period)))
+            })
+            .collect();
+    }
+
+    congruences
+        .into_iter()
+        .map(|(r, m)| {
+            // Lift each solution to the first one past every loop start.
+            match r < latest.start {
+                true => r + (latest.start - r).div_ceil(m) * m,
+                false => r,
+            }
+        })
+        .min()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = r#"
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+"#;
+
+    const TEST_DATA_REPEAT: &str = r#"
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+"#;
+
+    const TEST_DATA_GHOSTS: &str = r#"
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+"#;
+
+    /// Ghosts whose end nodes come at different offsets into loops of
+    /// different lengths, so the plain LCM of the first arrivals is wrong.
+    const TEST_DATA_OFFSETS: &str = r#"
+L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22D, 22D)
+22D = (22Z, 22Z)
+"#;
+
+    const PART1_RESULT: u64 = 2;
+    const PART2_RESULT: u64 = 2;
+
+    /// Moves every ghost one step at a time until they are all on Z nodes.
+    fn walk_together(data: &Data, limit: u64) -> Option<u64> {
+        let mut nodes = data
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.name.ends_with('A'))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        for (t, &turn) in (0..limit).zip(data.instructions.iter().cycle()) {
+            if nodes.iter().all(|&n| data.nodes[n].name.ends_with('Z')) {
+                return Some(t);
+            }
+            for n in &mut nodes {
+                *n = data.next(*n, turn);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(8) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part1_repeating_instructions() {
+        let parsed = input_generator(TEST_DATA_REPEAT).unwrap();
+
+        assert_eq!(part1(&parsed), 6u64);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_part2_ghosts() {
+        let parsed = input_generator(TEST_DATA_GHOSTS).unwrap();
+
+        assert_eq!(part2(&parsed), 6u64);
+        assert_eq!(walk_together(&parsed, 100), Some(6));
+    }
+
+    #[test]
+    fn test_part2_offsets() {
+        let parsed = input_generator(TEST_DATA_OFFSETS).unwrap();
+        let orbits = ["11A", "22A"]
+            .map(|s| parsed.orbit(parsed.find(s).unwrap(), |n| n.name.ends_with('Z')));
+
+        assert_eq!(
+            orbits[0],
+            Orbit {
+                start: 1,
+                period: 3,
+                prefix: vec![],
+                looping: vec![2],
+            }
+        );
+        assert_eq!(
+            orbits[1],
+            Orbit {
+                start: 1,
+                period: 4,
+                prefix: vec![],
+                looping: vec![1],
+            }
+        );
+        assert_eq!(part2(&parsed), 5u64);
+        assert_eq!(walk_together(&parsed, 100), Some(5));
+    }
+
+    #[test]
+    fn test_earliest_common() {
+        let orbit = |start, period, prefix: &[u64], looping: &[u64]| Orbit {
+            start,
+            period,
+            prefix: prefix.to_vec(),
+            looping: looping.to_vec(),
+        };
+
+        // Both on an end node before either loops.
+        assert_eq!(
+            earliest_common(&[orbit(5, 2, &[3], &[6]), orbit(4, 1, &[1, 3], &[4])]),
+            Some(3)
+        );
+        // Congruences 3 (mod 4) and 5 (mod 6), lifted past a late loop start.
+        assert_eq!(
+            earliest_common(&[orbit(0, 4, &[], &[3]), orbit(12, 6, &[], &[17])]),
+            Some(23)
+        );
+        // Congruences 0 (mod 2) and 1 (mod 4) never meet.
+        assert_eq!(
+            earliest_common(&[orbit(0, 2, &[], &[0]), orbit(0, 4, &[], &[1])]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unknown_node() {
+        assert!(input_generator("L\n\nAAA = (BBB, AAA)").is_err());
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day08", input_generator);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub instructions: Vec<Turn>,
+    pub nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// A node and the indices of the nodes its left and right turns lead to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub left: usize,
+    pub right: usize,
+}
+
+/// The step counts at which a walker is on an end node. Its state, node and
+/// position in the instructions, repeats from step `start` every `period`
+/// steps, so `prefix` holds the arrivals before `start` and `looping` those of
+/// the first lap, which recur forever.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Orbit {
+    pub start: u64,
+    pub period: u64,
+    pub prefix: Vec<u64>,
+    pub looping: Vec<u64>,
+}
+
+impl Orbit {
+    pub fn contains(&self, t: u64) -> bool {
+        match t.checked_sub(self.start) {
+            None => self.prefix.contains(&t),
+            Some(lap) => self
+                .looping
+                .iter()
+                .any(|&l| (l - self.start) == lap % self.period),
+        }
+    }
+}
+
+impl Data {
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|n| n.name == name)
+    }
+
+    pub fn next(&self, node: usize, turn: Turn) -> usize {
+        match turn {
+            Turn::Left => self.nodes[node].left,
+            Turn::Right => self.nodes[node].right,
+        }
+    }
+
+    /// Walks from `start` until the walker's state repeats, noting every step
+    /// count at which it stands on a node matching `is_end`.
+    pub fn orbit(&self, start: usize, is_end: impl Fn(&Node) -> bool) -> Orbit {
+        let len = self.instructions.len();
+        let mut seen = vec![None; self.nodes.len() * len];
+        let mut ends = Vec::new();
+        let mut node = start;
+        let mut i = 0;
+        for t in 0u64.. {
+            let state = node * len + i;
+            if let Some(first) = seen[state] {
+                let period = t - first;
+                tracing::debug!(
+                    start = %self.nodes[start].name,
+                    loop_start = first,
+                    period,
+                    ends = ?ends,
+                    "found loop"
+                );
+                let (prefix, looping) = ends.iter().partition(|&&e| e < first);
+                return Orbit {
+                    start: first,
+                    period,
+                    prefix,
+                    looping,
+                };
+            }
+            seen[state] = Some(t);
+            if is_end(&self.nodes[node]) {
+                ends.push(t);
+            }
+            node = self.next(node, self.instructions[i]);
+            i = (i + 1) % len;
+        }
+        unreachable!("There are only finitely many states")
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            'L' => Turn::Left,
+            'R' => Turn::Right,
+            _ => bail!("Unknown instruction {:?}", c),
+        })
+    }
+}
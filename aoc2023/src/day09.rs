@@ -0,0 +1,226 @@
+use anyhow::{bail, Context};
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Vec<Vec<i64>>;
+type OutputData = Answer;
+
+#[aoc_generator(day9)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    parse::normalise(input)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let history = parse::numbers::<i64>(line)
+                .with_context(|| format!("Could not parse history {}", i + 1))?;
+            if history.is_empty() {
+                bail!("History {} is empty", i + 1);
+            }
+            Ok(history)
+        })
+        .collect()
+}
+
+#[aoc(day9, part1)]
+pub fn part1(input: &[Vec<i64>]) -> OutputData {
+    Answer::from(sum(input, next_value))
+}
+
+/// `part1` by Lagrange interpolation rather than difference tables.
+#[aoc(day9, part1, lagrange)]
+pub fn part1_lagrange(input: &[Vec<i64>]) -> OutputData {
+    Answer::from(sum(input, next_value_lagrange))
+}
+
+#[aoc(day9, part2)]
+pub fn part2(input: &[Vec<i64>]) -> OutputData {
+    Answer::from(sum(input, |h| next_value(&reversed(h))))
+}
+
+/// `part2` by Lagrange interpolation rather than difference tables.
+#[aoc(day9, part2, lagrange)]
+pub fn part2_lagrange(input: &[Vec<i64>]) -> OutputData {
+    Answer::from(sum(input, |h| next_value_lagrange(&reversed(h))))
+}
+
+/// Sum of the extrapolated values of every history, panicking on overflow.
+fn sum(input: &[Vec<i64>], extrapolate: impl Fn(&[i64]) -> Option<i64>) -> i64 {
+    input
+        .iter()
+        .map(|h| extrapolate(h).expect("Extrapolated value overflowed"))
+        .try_fold(0i64, |sum, v| sum.checked_add(v))
+        .expect("Sum of extrapolated values overflowed")
+}
+
+/// A history read backwards. Extrapolating it forwards gives the value
+/// before the original's first one.
+fn reversed(history: &[i64]) -> Vec<i64> {
+    history.iter().rev().copied().collect()
+}
+
+/// The value after the last one in `history`, from its table of differences:
+/// the sum of the last value of every row until a row is all zeros. `None` on
+/// overflow.
+pub fn next_value(history: &[i64]) -> Option<i64> {
+    let mut row = history.to_vec();
+    let mut next = 0i64;
+    while row.iter().any(|&v| v != 0) {
+        next = next.checked_add(*row.last()?)?;
+        row = row
+            .windows(2)
+            .map(|w| w[1].checked_sub(w[0]))
+            .collect::<Option<_>>()?;
+    }
+    Some(next)
+}
+
+/// The value after the last one in `history`, evaluating at `n` the
+/// polynomial of degree below `n` through the `n` values, which is what the
+/// difference table extrapolates. With the points at `0..n`, Lagrange's
+/// formula reduces to `f(n) = Σ (-1)^(n-1-i) C(n, i) y_i`. `None` on overflow,
+/// including of the binomial coefficients, which outgrow an `i128` for
+/// histories of more than about 125 values.
+pub fn next_value_lagrange(history: &[i64]) -> Option<i64> {
+    let n = i128::try_from(history.len()).ok()?;
+    let mut binomial = 1i128;
+    let mut next = 0i128;
+    for (i, &y) in (0i128..).zip(history) {
+        let term = binomial.checked_mul(i128::from(y))?;
+        next = match (n - 1 - i) % 2 {
+            0 => next.checked_add(term)?,
+            _ => next.checked_sub(term)?,
+        };
+        binomial = binomial.checked_mul(n - i)? / (i + 1);
+    }
+    i64::try_from(next).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::random::{self, HistoriesConfig};
+    use aoc_common::differential::find_divergence;
+
+    const TEST_DATA: &str = r#"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"#;
+
+    const PART1_RESULT: u64 = 114;
+    const PART2_RESULT: u64 = 2;
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(9) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_part1_lagrange() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1_lagrange(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2_lagrange() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2_lagrange(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(next_value(&[10, 13, 16, 21, 30, 45]), Some(68));
+        assert_eq!(next_value(&reversed(&[10, 13, 16, 21, 30, 45])), Some(5));
+        assert_eq!(next_value(&[-4, -1, 4, 11]), Some(20));
+        assert_eq!(next_value_lagrange(&[-4, -1, 4, 11]), Some(20));
+        // A single value, or one the table never reduces to zeros.
+        assert_eq!(next_value(&[7]), Some(7));
+        assert_eq!(next_value_lagrange(&[7]), Some(7));
+        assert_eq!(next_value(&[1, 0, 0]), Some(1));
+        assert_eq!(next_value_lagrange(&[1, 0, 0]), Some(1));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(next_value(&[0, i64::MAX]), None);
+        assert_eq!(next_value(&[i64::MIN, 0]), None);
+        assert_eq!(next_value_lagrange(&[0, i64::MAX]), None);
+        assert_eq!(next_value(&[i64::MAX, i64::MAX - 1]), Some(i64::MAX - 2));
+        assert_eq!(
+            next_value_lagrange(&[i64::MAX, i64::MAX - 1]),
+            Some(i64::MAX - 2)
+        );
+    }
+
+    #[test]
+    fn test_extrapolate_differential() {
+        let config = |scale: usize| HistoriesConfig {
+            histories: 5,
+            length: 4 * scale,
+            max_degree: 3 * scale,
+            ..Default::default()
+        };
+        let answers = |extrapolate: fn(&[i64]) -> Option<i64>| {
+            move |input: &str| {
+                input_generator(input).ok().map(|d| {
+                    d.iter()
+                        .flat_map(|h| [extrapolate(h), extrapolate(&reversed(h))])
+                        .collect::<Vec<_>>()
+                })
+            }
+        };
+        let divergence = find_divergence(
+            1..6,
+            0..20,
+            |rng, scale| random::day09(rng, &config(scale)),
+            answers(next_value),
+            answers(next_value_lagrange),
+        );
+
+        assert!(divergence.is_none(), "{}", divergence.unwrap());
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day09", input_generator);
+    }
+}
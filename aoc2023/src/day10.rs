@@ -0,0 +1,414 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context};
+use aoc_common::{
+    answer::Answer,
+    grid::{Direction, Glyph, Grid, Pos},
+};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Data;
+type OutputData = Answer;
+
+#[aoc_generator(day10)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    let mut grid = Grid::parse(input, Tile::try_from)?;
+    let mut starts = grid.positions().filter(|&p| grid[p] == Tile::Start);
+    let start = starts.next().context("No start tile")?;
+    if starts.next().is_some() {
+        bail!("More than one start tile");
+    }
+
+    // The start is whichever pipe joins two of the neighbours leading into it
+    // in a loop. A stray pipe may point at it too, so try every pair.
+    let joined = Direction::ALL
+        .into_iter()
+        .filter(|&d| {
+            grid.step(start, d)
+                .and_then(|p| grid[p].connections())
+                .is_some_and(|c| c.contains(&d.opposite()))
+        })
+        .collect::<Vec<_>>();
+    let mut error = None;
+    for (i, &a) in joined.iter().enumerate() {
+        for &b in &joined[i + 1..] {
+            match trace(&grid, start, a, b) {
+                Ok(path) => {
+                    grid[start] = Tile::joining(a, b);
+                    return Ok(Data { grid, start, path });
+                }
+                Err(e) => error = error.or(Some(e)),
+            }
+        }
+    }
+
+    match error {
+        Some(e) => Err(e.context("No pair of pipes joining the start closes a loop")),
+        None => bail!("Start joins {} pipes, expected at least 2", joined.len()),
+    }
+}
+
+/// The loop leaving `start` towards `out`, which must come back into it from
+/// `back`.
+fn trace(
+    grid: &Grid<Tile>,
+    start: Pos,
+    out: Direction,
+    back: Direction,
+) -> anyhow::Result<Vec<Pos>> {
+    let mut path = vec![start];
+    let mut pos = start;
+    let mut heading = out;
+    loop {
+        pos = grid.step(pos, heading).context("Loop leaves the grid")?;
+        if pos == start {
+            if heading.opposite() != back {
+                bail!("Loop returns to the start from {:?}", heading.opposite());
+            }
+            return Ok(path);
+        }
+        let [a, b] = grid[pos]
+            .connections()
+            .with_context(|| format!("Loop breaks at {:?}", pos))?;
+        heading = match heading.opposite() {
+            from if from == a => b,
+            from if from == b => a,
+            _ => bail!("Pipe at {:?} does not join the loop", pos),
+        };
+        path.push(pos);
+        if path.len() > grid.cells().len() {
+            bail!("Loop never returns to the start");
+        }
+    }
+}
+
+#[aoc(day10, part1)]
+pub fn part1(input: &ChallangeData) -> OutputData {
+    Answer::from(input.path.len() / 2)
+}
+
+/// Tiles enclosed by the loop, counted along every row: crossing a loop
+/// tile that joins north flips between outside and inside.
+#[aoc(day10, part2)]
+pub fn part2(input: &ChallangeData) -> OutputData {
+    let on_loop = input.path.iter().collect::<HashSet<_>>();
+    let mut enclosed = 0u64;
+    for y in 0..input.grid.height() {
+        let mut inside = false;
+        for x in 0..input.grid.width() {
+            let pos = Pos::new(x, y);
+            match on_loop.contains(&pos) {
+                true if input.grid[pos].joins(Direction::North) => inside = !inside,
+                true => {}
+                false if inside => enclosed += 1,
+                false => {}
+            }
+        }
+    }
+
+    Answer::from(enclosed)
+}
+
+/// `part2` from the loop's area by the shoelace formula, and Pick's theorem
+/// `A = i + b/2 - 1` for the tiles `i` strictly inside it, `b` being the
+/// tiles on the loop.
+#[aoc(day10, part2, shoelace)]
+pub fn part2_shoelace(input: &ChallangeData) -> OutputData {
+    let coords = |p: &Pos| {
+        (
+            i64::try_from(p.x).expect("Grid too wide"),
+            i64::try_from(p.y).expect("Grid too tall"),
+        )
+    };
+    let twice_area = input
+        .path
+        .iter()
+        .zip(input.path.iter().cycle().skip(1))
+        .map(|(a, b)| {
+            let ((ax, ay), (bx, by)) = (coords(a), coords(b));
+            ax.checked_mul(by)?.checked_sub(bx.checked_mul(ay)?)
+        })
+        .try_fold(0i64, |sum, term| sum.checked_add(term?))
+        .expect("Loop area overflowed")
+        .unsigned_abs();
+    let boundary = u64::try_from(input.path.len()).expect("Loop too long");
+
+    // i = A - b/2 + 1, kept in whole numbers as (2A - b + 2) / 2.
+    let enclosed = twice_area
+        .checked_add(2)
+        .and_then(|a| a.checked_sub(boundary))
+        .expect("Loop area overflowed")
+        / 2;
+    Answer::from(enclosed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = r#"
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+"#;
+
+    const PART1_RESULT: u64 = 8;
+    const PART2_RESULT: u64 = 1;
+
+    /// The official part 1 examples, a simple loop surrounded by pipes that
+    /// are not on it, and the more complex one above.
+    const PART1_EXAMPLES: [(&str, u64); 2] = [
+        (
+            r#"
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+"#,
+            4,
+        ),
+        (TEST_DATA, 8),
+    ];
+
+    /// The official part 2 examples: an enclosed area with a gap, the gap
+    /// squeezed between pipes, a larger loop and one among junk pipes.
+    const PART2_EXAMPLES: [(&str, u64); 4] = [
+        (
+            r#"
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+"#,
+            4,
+        ),
+        (
+            r#"
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+"#,
+            4,
+        ),
+        (
+            r#"
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+"#,
+            8,
+        ),
+        (
+            r#"
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+"#,
+            10,
+        ),
+    ];
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(10) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        for (input, expected) in PART1_EXAMPLES {
+            let parsed = input_generator(input).unwrap();
+
+            assert_eq!(part1(&parsed), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        for (input, expected) in PART2_EXAMPLES {
+            let parsed = input_generator(input).unwrap();
+
+            assert_eq!(part2(&parsed), expected, "{}", input);
+            assert_eq!(part2_shoelace(&parsed), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_start_inferred() {
+        let parsed = input_generator(PART1_EXAMPLES[0].0).unwrap();
+
+        assert_eq!(parsed.start, Pos::new(1, 1));
+        assert_eq!(parsed.grid[parsed.start], Tile::SouthEast);
+        assert_eq!(parsed.path.len(), 8);
+
+        // A stray pipe pointing into the start is not part of the loop.
+        let parsed = input_generator(".|...\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap();
+
+        assert_eq!(parsed.grid[parsed.start], Tile::SouthEast);
+        assert_eq!(parsed.path.len(), 8);
+    }
+
+    #[test]
+    fn test_broken_loops() {
+        // No pair of the start's four pipes closes a loop, the loop leaves the
+        // grid or breaks, and two starts.
+        assert!(input_generator(".|.\n-S-\n.|.").is_err());
+        assert!(input_generator("S-\n|.").is_err());
+        assert!(input_generator("S-7\n|.|\nL-.").is_err());
+        assert!(input_generator("S7\nLJ\nS.").is_err());
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day10", input_generator);
+    }
+}
+
+/// The maze with the start tile replaced by the pipe it stands for, and the
+/// loop through it in order, starting at the start.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data {
+    pub grid: Grid<Tile>,
+    pub start: Pos,
+    pub path: Vec<Pos>,
+}
+
+/// Pipes are named after the two directions they join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Ground,
+    Start,
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+}
+
+impl Tile {
+    const PIPES: [(Tile, [Direction; 2]); 6] = [
+        (Tile::NorthSouth, [Direction::North, Direction::South]),
+        (Tile::EastWest, [Direction::East, Direction::West]),
+        (Tile::NorthEast, [Direction::North, Direction::East]),
+        (Tile::NorthWest, [Direction::North, Direction::West]),
+        (Tile::SouthWest, [Direction::South, Direction::West]),
+        (Tile::SouthEast, [Direction::South, Direction::East]),
+    ];
+
+    /// The two directions a pipe joins, `None` for ground and the start.
+    pub fn connections(self) -> Option<[Direction; 2]> {
+        Self::PIPES
+            .iter()
+            .find(|(tile, _)| *tile == self)
+            .map(|(_, c)| *c)
+    }
+
+    pub fn joins(self, direction: Direction) -> bool {
+        self.connections().is_some_and(|c| c.contains(&direction))
+    }
+
+    /// The pipe joining two different directions.
+    pub fn joining(a: Direction, b: Direction) -> Tile {
+        Self::PIPES
+            .iter()
+            .find(|(_, c)| c.contains(&a) && c.contains(&b) && a != b)
+            .map(|(tile, _)| *tile)
+            .unwrap_or_else(|| panic!("No pipe joins {:?} and {:?}", a, b))
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Tile::Ground,
+            'S' => Tile::Start,
+            '|' => Tile::NorthSouth,
+            '-' => Tile::EastWest,
+            'L' => Tile::NorthEast,
+            'J' => Tile::NorthWest,
+            '7' => Tile::SouthWest,
+            'F' => Tile::SouthEast,
+            _ => bail!("Unknown tile {:?}", c),
+        })
+    }
+}
+
+impl Glyph for Tile {
+    fn glyph(&self) -> char {
+        match self {
+            Tile::Ground => '.',
+            Tile::Start => 'S',
+            Tile::NorthSouth => '|',
+            Tile::EastWest => '-',
+            Tile::NorthEast => 'L',
+            Tile::NorthWest => 'J',
+            Tile::SouthWest => '7',
+            Tile::SouthEast => 'F',
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use anyhow::bail;
+use aoc_common::{
+    answer::Answer,
+    grid::{Colour, Glyph, Grid},
+};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Data;
+type OutputData = Answer;
+
+#[aoc_generator(day11)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    Ok(Data(Grid::parse(input, Cell::try_from)?))
+}
+
+#[aoc(day11, part1)]
+pub fn part1(input: &ChallangeData) -> OutputData {
+    Answer::from(input.sum_of_distances(2))
+}
+
+#[aoc(day11, part2)]
+pub fn part2(input: &ChallangeData) -> OutputData {
+    Answer::from(input.sum_of_distances(1_000_000))
+}
+
+/// Sum of the distances between every pair of galaxies along one axis, given
+/// the number of galaxies in each row or column. Every empty line counts as
+/// `expansion` lines.
+///
+/// Walking the lines in order, a galaxy at `p` is `p - q` from each of the
+/// galaxies seen before it, so it adds `p * seen - sum` with `sum` the running
+/// total of their positions: linear in the lines, whatever the galaxy count.
+/// `None` on overflow.
+fn axis_distances(counts: &[u64], expansion: u64) -> Option<u128> {
+    let expansion = u128::from(expansion);
+    let (mut pos, mut seen, mut sum, mut total) = (0u128, 0u128, 0u128, 0u128);
+    for count in counts.iter().copied().map(u128::from) {
+        let distances = pos.checked_mul(seen)?.checked_sub(sum)?;
+        total = total.checked_add(count.checked_mul(distances)?)?;
+        seen = seen.checked_add(count)?;
+        sum = sum.checked_add(count.checked_mul(pos)?)?;
+        pos = pos.checked_add(if count == 0 { expansion } else { 1 })?;
+    }
+    Some(total)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::grid::Pos;
+
+    const TEST_DATA: &str = r#"
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+"#;
+
+    const PART1_RESULT: u64 = 374;
+    const PART2_RESULT: u64 = 82000210;
+
+    /// Reference for `sum_of_distances`: expands every galaxy's coordinates and
+    /// sums the distances of every pair.
+    fn pairwise(data: &Data, expansion: u64) -> u64 {
+        let grid = &data.0;
+        let galaxy = |x, y| grid[Pos::new(x, y)] == Cell::Galaxy;
+        let empty_rows = (0..grid.height())
+            .map(|y| (0..grid.width()).all(|x| !galaxy(x, y)))
+            .collect::<Vec<_>>();
+        let empty_cols = (0..grid.width())
+            .map(|x| (0..grid.height()).all(|y| !galaxy(x, y)))
+            .collect::<Vec<_>>();
+        let expand = |empty: &[bool], i: usize| {
+            empty[..i]
+                .iter()
+                .map(|&e| if e { expansion } else { 1 })
+                .sum::<u64>()
+        };
+        let galaxies = grid
+            .positions()
+            .filter(|&p| grid[p] == Cell::Galaxy)
+            .map(|p| (expand(&empty_cols, p.x), expand(&empty_rows, p.y)))
+            .collect::<Vec<_>>();
+
+        let mut total = 0;
+        for (i, a) in galaxies.iter().enumerate() {
+            for b in &galaxies[i + 1..] {
+                total += a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+            }
+        }
+        total
+    }
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(11) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_expansion_factors() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        assert_eq!(parsed.sum_of_distances(10), 1030);
+        assert_eq!(parsed.sum_of_distances(100), 8410);
+        for expansion in [1, 2, 10, 100, 1_000_000] {
+            assert_eq!(
+                parsed.sum_of_distances(expansion),
+                pairwise(&parsed, expansion)
+            );
+        }
+    }
+
+    #[test]
+    fn test_axis_overflow() {
+        assert_eq!(
+            axis_distances(&[1, 0, 1], u64::MAX),
+            Some(u128::from(u64::MAX) + 1)
+        );
+        assert_eq!(axis_distances(&[u64::MAX, 0, u64::MAX], u64::MAX), None);
+    }
+
+    #[test]
+    fn test_pairwise_agrees() {
+        let inputs = [
+            "#",
+            "#.#",
+            "#\n.\n#",
+            "..\n..",
+            "#..\n...\n..#",
+            ".#.\n#.#\n...",
+        ];
+        for input in inputs {
+            let parsed = input_generator(input).unwrap();
+            for expansion in [1, 2, 7] {
+                assert_eq!(
+                    parsed.sum_of_distances(expansion),
+                    pairwise(&parsed, expansion),
+                    "{:?} expanded {}",
+                    input,
+                    expansion
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day11", input_generator);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data(Grid<Cell>);
+
+impl Data {
+    /// Sum of the shortest distances between every pair of galaxies, once
+    /// every empty row and column has grown to `expansion` of them.
+    pub fn sum_of_distances(&self, expansion: u64) -> u64 {
+        let grid = &self.0;
+        let mut rows = vec![0u64; grid.height()];
+        let mut columns = vec![0u64; grid.width()];
+        for pos in grid.positions().filter(|&p| grid[p] == Cell::Galaxy) {
+            rows[pos.y] += 1;
+            columns[pos.x] += 1;
+        }
+
+        axis_distances(&rows, expansion)
+            .zip(axis_distances(&columns, expansion))
+            .and_then(|(rows, columns)| rows.checked_add(columns))
+            .and_then(|total| u64::try_from(total).ok())
+            .expect("Sum of distances overflowed")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Galaxy,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Cell::Empty,
+            '#' => Cell::Galaxy,
+            _ => bail!("Unknown cell {:?}", c),
+        })
+    }
+}
+
+impl Glyph for Cell {
+    fn glyph(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Galaxy => '#',
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            Cell::Empty => Colour::Grey,
+            Cell::Galaxy => Colour::Yellow,
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use aoc_common::{answer::Answer, parse};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Vec<Record>;
+type OutputData = Answer;
+
+const UNFOLD: usize = 5;
+
+/// Rows with more unknown springs than this are too slow to brute force.
+pub const BRUTE_FORCE_LIMIT: usize = 20;
+
+#[aoc_generator(day12)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    parse::normalise(input)
+        .lines()
+        .map(Record::from_str)
+        .collect()
+}
+
+#[aoc(day12, part1)]
+pub fn part1(input: &[Record]) -> OutputData {
+    Answer::from(total(input.iter().map(Record::arrangements)))
+}
+
+#[aoc(day12, part2)]
+pub fn part2(input: &[Record]) -> OutputData {
+    Answer::from(total(input.iter().map(|r| r.unfold(UNFOLD).arrangements())))
+}
+
+fn total(counts: impl Iterator<Item = u64>) -> u64 {
+    counts
+        .inspect(|count| tracing::trace!(count, "arrangements"))
+        .try_fold(0u64, |sum, c| sum.checked_add(c))
+        .expect("Sum of arrangements overflowed")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::random::{self, SpringsConfig};
+    use aoc_common::differential::find_divergence;
+
+    const TEST_DATA: &str = r#"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+"#;
+
+    const PART1_RESULT: u64 = 21;
+    const PART2_RESULT: u64 = 525152;
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(12) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_rows() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        assert_eq!(
+            parsed.iter().map(Record::arrangements).collect::<Vec<_>>(),
+            vec![1, 4, 1, 1, 4, 10]
+        );
+        assert_eq!(
+            parsed
+                .iter()
+                .map(|r| r.unfold(UNFOLD).arrangements())
+                .collect::<Vec<_>>(),
+            vec![1, 16384, 1, 16, 2500, 506250]
+        );
+    }
+
+    #[test]
+    fn test_brute_force() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        for record in &parsed {
+            assert_eq!(
+                record.arrangements_brute_force(),
+                Some(record.arrangements())
+            );
+            let unfolded = record.unfold(2);
+            if let Some(count) = unfolded.arrangements_brute_force() {
+                assert_eq!(count, unfolded.arrangements());
+            }
+        }
+
+        let huge = Record::from_str(&format!("{} 1", "?".repeat(BRUTE_FORCE_LIMIT + 1))).unwrap();
+        assert_eq!(huge.arrangements_brute_force(), None);
+        assert_eq!(huge.arrangements(), 21);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let count = |s: &str| Record::from_str(s).unwrap().arrangements();
+
+        assert_eq!(count("# 1"), 1);
+        assert_eq!(count(". 1"), 0);
+        assert_eq!(count("## 1"), 0);
+        assert_eq!(count("#.# 1"), 0);
+        assert_eq!(count("??? 1,1"), 1);
+        assert_eq!(count("?.# 1"), 1);
+        assert!(Record::from_str("??? 0").is_err());
+        assert!(Record::from_str("?x? 1").is_err());
+    }
+
+    #[test]
+    fn test_arrangements_differential() {
+        let config = |scale: usize| SpringsConfig {
+            records: 5,
+            max_len: 4 * scale,
+            ..Default::default()
+        };
+        let divergence = find_divergence(
+            1..6,
+            0..20,
+            |rng, scale| random::day12(rng, &config(scale)),
+            |input| {
+                input_generator(input).ok().and_then(|d| {
+                    d.iter()
+                        .map(Record::arrangements_brute_force)
+                        .collect::<Option<Vec<_>>>()
+                })
+            },
+            |input| {
+                input_generator(input)
+                    .ok()
+                    .map(|d| d.iter().map(Record::arrangements).collect::<Vec<_>>())
+            },
+        );
+
+        assert!(divergence.is_none(), "{}", divergence.unwrap());
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day12", input_generator);
+    }
+}
+
+/// One row of the condition records: the springs, and the lengths of the
+/// runs of damaged springs in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub springs: Vec<Spring>,
+    pub groups: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Record {
+    /// The record repeated `times`, the copies of the springs separated by
+    /// unknown ones.
+    pub fn unfold(&self, times: usize) -> Record {
+        let mut springs = self.springs.clone();
+        for _ in 1..times {
+            springs.push(Spring::Unknown);
+            springs.extend_from_slice(&self.springs);
+        }
+        Record {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    /// Number of ways to fill in the unknown springs matching the groups.
+    ///
+    /// `count(i, g)` is the number of arrangements of the springs from `i`
+    /// on with the groups from `g` on. Each state is solved once, so this is
+    /// `O(springs * groups * longest group)`.
+    pub fn arrangements(&self) -> u64 {
+        let mut memo = vec![None; (self.springs.len() + 1) * (self.groups.len() + 1)];
+        self.count(0, 0, &mut memo)
+    }
+
+    fn count(&self, i: usize, g: usize, memo: &mut [Option<u64>]) -> u64 {
+        let springs = self.springs.get(i..).unwrap_or_default();
+        let Some(&len) = self.groups.get(g) else {
+            // Every group placed, the rest must all be operational.
+            return u64::from(!springs.contains(&Spring::Damaged));
+        };
+        let Some(first) = springs.first() else {
+            return 0;
+        };
+        let key = i * (self.groups.len() + 1) + g;
+        if let Some(count) = memo[key] {
+            return count;
+        }
+
+        let mut count = 0u64;
+        if *first != Spring::Damaged {
+            count = self.count(i + 1, g, memo);
+        }
+        // Place the group here: `len` springs that may be damaged, followed
+        // by the end of the row or one that may be operational.
+        let fits = springs.len() >= len
+            && !springs[..len].contains(&Spring::Operational)
+            && springs.get(len) != Some(&Spring::Damaged);
+        if *first != Spring::Operational && fits {
+            count = count
+                .checked_add(self.count(i + len + 1, g + 1, memo))
+                .expect("Arrangement count overflowed");
+        }
+
+        memo[key] = Some(count);
+        count
+    }
+
+    /// [`Record::arrangements`] by trying every way of filling in the unknown
+    /// springs, `None` if there are more than [`BRUTE_FORCE_LIMIT`].
+    pub fn arrangements_brute_force(&self) -> Option<u64> {
+        let unknown = self
+            .springs
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| **s == Spring::Unknown)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if unknown.len() > BRUTE_FORCE_LIMIT {
+            return None;
+        }
+
+        let mut springs = self.springs.clone();
+        let mut count = 0;
+        for mask in 0u32..1 << unknown.len() {
+            for (bit, &i) in unknown.iter().enumerate() {
+                springs[i] = match (mask >> bit) & 1 {
+                    1 => Spring::Damaged,
+                    _ => Spring::Operational,
+                };
+            }
+            let groups = springs
+                .split(|s| *s == Spring::Operational)
+                .map(<[Spring]>::len)
+                .filter(|&len| len > 0)
+                .collect::<Vec<_>>();
+            if groups == self.groups {
+                count += 1;
+            }
+        }
+        Some(count)
+    }
+}
+
+impl FromStr for Record {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (springs, groups) = s
+            .split_once(' ')
+            .context("Missing space between springs and groups")?;
+        let springs = springs
+            .chars()
+            .map(Spring::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let groups = groups
+            .trim()
+            .split(',')
+            .map(|n| {
+                let len = n
+                    .trim()
+                    .parse::<usize>()
+                    .with_context(|| format!("Could not parse group {:?}", n))?;
+                if len == 0 {
+                    bail!("Empty group");
+                }
+                Ok(len)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Record { springs, groups })
+    }
+}
+
+impl TryFrom<char> for Spring {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Spring::Operational,
+            '#' => Spring::Damaged,
+            '?' => Spring::Unknown,
+            _ => bail!("Unknown spring {:?}", c),
+        })
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+
+use anyhow::bail;
+use aoc_common::{
+    answer::Answer,
+    grid::{Colour, Direction, Glyph, Grid, Pos},
+    visual::Renderer,
+};
+use aoc_runner_derive::{aoc, aoc_generator};
+
+type ChallangeData = Data;
+type OutputData = Answer;
+
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+/// The tilts of one spin cycle, in order.
+const SPIN: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+#[aoc_generator(day14)]
+pub fn input_generator(input: &str) -> anyhow::Result<ChallangeData> {
+    Ok(Data(Grid::parse(input, Tile::try_from)?))
+}
+
+#[aoc(day14, part1)]
+pub fn part1(input: &ChallangeData) -> OutputData {
+    let mut grid = input.0.clone();
+    tilt(&mut grid, Direction::North);
+    Answer::from(load(&grid))
+}
+
+#[aoc(day14, part2)]
+pub fn part2(input: &ChallangeData) -> OutputData {
+    let mut grid = input.0.clone();
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    for cycle in 0..SPIN_CYCLES {
+        if let Some(&start) = seen.get(&grid) {
+            let period = cycle - start;
+            tracing::debug!(start, period, "found spin cycle loop");
+            let end = &history[start + (SPIN_CYCLES - start) % period];
+            return Answer::from(load(end));
+        }
+        seen.insert(grid.clone(), cycle);
+        history.push(grid.clone());
+        spin(&mut grid);
+    }
+
+    Answer::from(load(&grid))
+}
+
+/// Renders the rocks rolling through every tilt of the spin cycles, until the
+/// platform comes back to a state it was already in.
+pub fn visualise(input: &ChallangeData, renderer: &mut Renderer) -> anyhow::Result<()> {
+    let mut grid = input.0.clone();
+    let mut seen = HashMap::new();
+    renderer.frame(&grid, &format!("Start, load {}", load(&grid)))?;
+    for cycle in 1.. {
+        seen.insert(grid.clone(), cycle);
+        for direction in SPIN {
+            tilt(&mut grid, direction);
+            let caption = format!(
+                "Cycle {}, tilted {:?}, load {}",
+                cycle,
+                direction,
+                load(&grid)
+            );
+            renderer.frame(&grid, &caption)?;
+        }
+        if let Some(start) = seen.get(&grid) {
+            let caption = format!(
+                "Cycle {} repeats the state after cycle {}",
+                cycle,
+                start - 1
+            );
+            renderer.frame(&grid, &caption)?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::visual::RenderConfig;
+
+    const TEST_DATA: &str = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#;
+
+    const PART1_RESULT: u64 = 136;
+    const PART2_RESULT: u64 = 64;
+
+    #[test]
+    fn test_parser_example() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+
+        insta::assert_debug_snapshot!(parsed);
+    }
+
+    #[test]
+    fn test_parser_real_input() {
+        if let Some(input) = crate::snapshot::real_input(14) {
+            insta::assert_debug_snapshot!(input_generator(&input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part1(&parsed);
+
+        assert_eq!(result, PART1_RESULT);
+    }
+
+    #[test]
+    fn test_part2() {
+        let parsed = input_generator(TEST_DATA).unwrap();
+        let result = part2(&parsed);
+
+        assert_eq!(result, PART2_RESULT);
+    }
+
+    #[test]
+    fn test_spin() {
+        let mut grid = input_generator(TEST_DATA).unwrap().0;
+        spin(&mut grid);
+
+        assert_eq!(
+            grid.to_text(),
+            ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n\
+             .O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....\n"
+        );
+    }
+
+    #[test]
+    fn test_visualise_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc-day14-{}", std::process::id()));
+        let config = RenderConfig {
+            dump_dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let mut renderer = Renderer::new(config, None).unwrap();
+        visualise(&input_generator(TEST_DATA).unwrap(), &mut renderer).unwrap();
+
+        let frame = |n: usize| std::fs::read_to_string(dir.join(format!("frame_{:05}.txt", n)));
+        let north = frame(1).unwrap();
+        assert!(north.starts_with("Cycle 1, tilted North, load 136\n"));
+        let mut tilted = input_generator(TEST_DATA).unwrap().0;
+        tilt(&mut tilted, Direction::North);
+        assert!(north.ends_with(&tilted.to_text()));
+        assert!(frame(renderer.frames() - 1)
+            .unwrap()
+            .starts_with("Cycle 10 repeats the state after cycle 3"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parser_crlf() {
+        let crlf = TEST_DATA.replace('\n', "\r\n").replace(' ', "\t");
+
+        assert_eq!(
+            input_generator(&crlf).unwrap(),
+            input_generator(TEST_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parser_fuzz_corpus() {
+        crate::fuzz::check_corpus("day14", input_generator);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Data(Grid<Tile>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Empty,
+    Round,
+    Cube,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Tile::Empty,
+            'O' => Tile::Round,
+            '#' => Tile::Cube,
+            _ => bail!("Unknown tile {:?}", c),
+        })
+    }
+}
+
+impl Glyph for Tile {
+    fn glyph(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Round => 'O',
+            Tile::Cube => '#',
+        }
+    }
+
+    fn colour(&self) -> Colour {
+        match self {
+            Tile::Empty => Colour::Grey,
+            Tile::Round => Colour::Yellow,
+            Tile::Cube => Colour::Blue,
+        }
+    }
+}
+
+/// Rolls every round rock as far as it goes towards `direction`.
+pub fn tilt(grid: &mut Grid<Tile>, direction: Direction) {
+    let (width, height) = (grid.width(), grid.height());
+    // Each lane is a column or row, walked from the edge the rocks roll to.
+    let (lanes, len) = match direction {
+        Direction::North | Direction::South => (width, height),
+        Direction::East | Direction::West => (height, width),
+    };
+    let pos = |lane: usize, i: usize| match direction {
+        Direction::North => Pos::new(lane, i),
+        Direction::South => Pos::new(lane, height - 1 - i),
+        Direction::West => Pos::new(i, lane),
+        Direction::East => Pos::new(width - 1 - i, lane),
+    };
+
+    for lane in 0..lanes {
+        let mut free = 0;
+        for i in 0..len {
+            match grid[pos(lane, i)] {
+                Tile::Cube => free = i + 1,
+                Tile::Round => {
+                    grid[pos(lane, i)] = Tile::Empty;
+                    grid[pos(lane, free)] = Tile::Round;
+                    free += 1;
+                }
+                Tile::Empty => {}
+            }
+        }
+    }
+}
+
+/// One spin cycle: north, west, south then east.
+pub fn spin(grid: &mut Grid<Tile>) {
+    for direction in SPIN {
+        tilt(grid, direction);
+    }
+}
+
+/// Load on the north support beams.
+pub fn load(grid: &Grid<Tile>) -> u64 {
+    grid.positions()
+        .filter(|&p| grid[p] == Tile::Round)
+        .map(|p| u64::try_from(grid.height() - p.y).expect("Grid too tall"))
+        .try_fold(0u64, |sum, l| sum.checked_add(l))
+        .expect("Load overflowed")
+}
@@ -0,0 +1,10 @@
+//! Replays this crate's fuzz corpus in `fuzz/corpus`.
+
+use std::path::Path;
+
+pub fn check_corpus<T>(target: &str, generator: fn(&str) -> T) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fuzz/corpus")
+        .join(target);
+    aoc_common::fuzz::check_corpus(&dir, generator);
+}
@@ -0,0 +1,28 @@
+use aoc_runner_derive::aoc_lib;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day14;
+pub mod random;
+pub mod registry;
+
+#[cfg(test)]
+mod fuzz;
+#[cfg(test)]
+mod snapshot;
+
+
+// extern crate aoc_runner;
+// extern crate aoc_runner_derive;
+
+aoc_lib! { year = 2023 }
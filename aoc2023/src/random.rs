@@ -0,0 +1,469 @@
+//! Seeded random 2023 puzzle inputs, for stress testing solvers far beyond the
+//! shipped examples and real inputs.
+//!
+//! Every generator produces text in the exact format of the real puzzle input
+//! and is fully determined by the [`Rng`] seed and its config, so a failing
+//! case can always be reproduced.
+
+use std::fmt::Write;
+
+pub use aoc_common::random::Rng;
+
+/// Scale of a day 2 game list.
+#[derive(Debug, Clone)]
+pub struct GamesConfig {
+    pub games: usize,
+    pub max_sets: usize,
+    pub max_cubes: u32,
+}
+
+impl Default for GamesConfig {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            max_sets: 6,
+            max_cubes: 20,
+        }
+    }
+}
+
+pub fn day02(rng: &mut Rng, config: &GamesConfig) -> String {
+    const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+    let mut out = String::new();
+    for id in 1..=config.games {
+        let sets = (0..rng.range(1..config.max_sets as u64 + 1))
+            .map(|_| {
+                let mut colours = COLOURS;
+                rng.shuffle(&mut colours);
+                let count = rng.range(1..4) as usize;
+                colours[..count]
+                    .iter()
+                    .map(|c| format!("{} {}", rng.range(1..config.max_cubes as u64 + 1), c))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "Game {}: {}", id, sets.join("; ")).unwrap();
+    }
+
+    out
+}
+
+/// Scale of a day 4 scratchcard list.
+///
+/// A card wins with `win_percent` probability, and then matches between 1 and
+/// `max_matches` numbers. Keep the expected matches per card below one, or
+/// the part 2 copy counts grow exponentially with `cards`.
+#[derive(Debug, Clone)]
+pub struct CardsConfig {
+    pub cards: usize,
+    pub winning_numbers: usize,
+    pub user_numbers: usize,
+    pub max_number: u32,
+    pub win_percent: u32,
+    pub max_matches: usize,
+}
+
+impl Default for CardsConfig {
+    fn default() -> Self {
+        Self {
+            cards: 200,
+            winning_numbers: 10,
+            user_numbers: 25,
+            max_number: 99,
+            win_percent: 15,
+            max_matches: 5,
+        }
+    }
+}
+
+pub fn day04(rng: &mut Rng, config: &CardsConfig) -> String {
+    let total = config.winning_numbers + config.user_numbers;
+    let width = config.max_number.to_string().len();
+    let card_width = config.cards.to_string().len();
+
+    let mut out = String::new();
+    for card in 1..=config.cards {
+        let numbers = rng.distinct(1..config.max_number as u64 + 1, total);
+        let (winning, others) = numbers.split_at(config.winning_numbers);
+
+        let max_matches = config
+            .max_matches
+            .min(config.cards - card)
+            .min(config.winning_numbers)
+            .min(config.user_numbers);
+        let matches = match max_matches > 0 && rng.chance(config.win_percent) {
+            true => rng.range(1..max_matches as u64 + 1) as usize,
+            false => 0,
+        };
+
+        let mut user = winning[..matches].to_vec();
+        user.extend(&others[..config.user_numbers - matches]);
+        rng.shuffle(&mut user);
+
+        let mut winning = winning.to_vec();
+        rng.shuffle(&mut winning);
+
+        let format = |nums: &[u64]| {
+            nums.iter()
+                .map(|n| format!("{:>width$}", n, width = width))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        writeln!(
+            out,
+            "Card {:>card_width$}: {} | {}",
+            card,
+            format(&winning),
+            format(&user),
+            card_width = card_width
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+/// Scale of a day 5 almanac. Part 2 of day 5 visits every seed in every range,
+/// so `max_seed_range_len` is the knob for its run time.
+#[derive(Debug, Clone)]
+pub struct AlmanacConfig {
+    pub seed_ranges: usize,
+    pub max_seed_range_len: u32,
+    pub mappings_per_map: usize,
+    pub max_value: u32,
+}
+
+impl Default for AlmanacConfig {
+    fn default() -> Self {
+        Self {
+            seed_ranges: 10,
+            max_seed_range_len: 1000,
+            mappings_per_map: 20,
+            max_value: 1_000_000,
+        }
+    }
+}
+
+pub fn day05(rng: &mut Rng, config: &AlmanacConfig) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    let max_value = config.max_value as u64;
+    let seeds = (0..config.seed_ranges)
+        .map(|_| {
+            let len = rng
+                .range(1..config.max_seed_range_len as u64 + 1)
+                .min(max_value);
+            let start = rng.range(0..max_value - len + 1);
+            format!("{} {}", start, len)
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        // Split [0, max_value) into consecutive source ranges and lay them out
+        // again in a shuffled order to get non-overlapping destinations.
+        let mut cuts = rng.distinct(1..max_value, config.mappings_per_map.saturating_sub(1));
+        cuts.push(0);
+        cuts.push(max_value);
+        cuts.sort_unstable();
+        let mut ranges = cuts
+            .windows(2)
+            .map(|w| (w[0], w[1] - w[0]))
+            .collect::<Vec<_>>();
+        let mut destinations = ranges.clone();
+        rng.shuffle(&mut destinations);
+
+        let mut dst = 0;
+        let mut placed = Vec::new();
+        for (start, len) in destinations {
+            placed.push((start, dst));
+            dst += len;
+        }
+        rng.shuffle(&mut ranges);
+
+        write!(out, "\n{} map:\n", name).unwrap();
+        for (src, len) in ranges {
+            // Leave some ranges unmapped so the identity fallback is exercised.
+            if rng.chance(10) {
+                continue;
+            }
+            let dst = placed.iter().find(|p| p.0 == src).unwrap().1;
+            writeln!(out, "{} {} {}", dst, src, len).unwrap();
+        }
+    }
+
+    out
+}
+
+/// Scale of a day 6 race sheet. Part 2 concatenates all the numbers, so the
+/// merged values only fit in a `u64` for a handful of short races.
+#[derive(Debug, Clone)]
+pub struct RacesConfig {
+    pub races: usize,
+    pub max_time: u64,
+}
+
+impl Default for RacesConfig {
+    fn default() -> Self {
+        Self {
+            races: 4,
+            max_time: 99,
+        }
+    }
+}
+
+pub fn day06(rng: &mut Rng, config: &RacesConfig) -> String {
+    let races = (0..config.races)
+        .map(|_| {
+            let time = rng.range(2..config.max_time + 1);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(0..best))
+        })
+        .collect::<Vec<_>>();
+
+    let width = races
+        .iter()
+        .map(|r| r.1.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let line = |nums: Vec<u64>| {
+        nums.iter()
+            .map(|n| format!("{:>width$}", n, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    format!(
+        "Time:      {}\nDistance:  {}\n",
+        line(races.iter().map(|r| r.0).collect()),
+        line(races.iter().map(|r| r.1).collect())
+    )
+}
+
+/// Scale of a day 9 report. Every history is a polynomial of degree at most
+/// `max_degree`, sampled at `0..length`, written in Newton form with
+/// coefficients up to `max_coefficient` so the values stay moderate.
+#[derive(Debug, Clone)]
+pub struct HistoriesConfig {
+    pub histories: usize,
+    pub length: usize,
+    pub max_degree: usize,
+    pub max_coefficient: i64,
+}
+
+impl Default for HistoriesConfig {
+    fn default() -> Self {
+        Self {
+            histories: 200,
+            length: 21,
+            max_degree: 10,
+            max_coefficient: 10,
+        }
+    }
+}
+
+pub fn day09(rng: &mut Rng, config: &HistoriesConfig) -> String {
+    let mut out = String::new();
+    for _ in 0..config.histories {
+        let degree = rng.range(0..config.max_degree as u64 + 1) as usize;
+        let coefficients = (0..=degree)
+            .map(|_| {
+                rng.range(0..2 * config.max_coefficient as u64 + 1) as i64 - config.max_coefficient
+            })
+            .collect::<Vec<_>>();
+        // y(x) = sum of c_k * C(x, k)
+        let values = (0..config.length as i64)
+            .map(|x| {
+                let mut binomial = 1;
+                let mut y = 0;
+                for (k, c) in (0..).zip(&coefficients) {
+                    y += c * binomial;
+                    binomial = binomial * (x - k) / (k + 1);
+                }
+                y.to_string()
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", values.join(" ")).unwrap();
+    }
+
+    out
+}
+
+/// Scale of a day 12 condition record list. Rows are drawn fully known, with
+/// `damaged_percent` of the springs damaged, then `unknown_percent` of them
+/// are hidden, so every row has at least one arrangement.
+#[derive(Debug, Clone)]
+pub struct SpringsConfig {
+    pub records: usize,
+    pub max_len: usize,
+    pub damaged_percent: u32,
+    pub unknown_percent: u32,
+}
+
+impl Default for SpringsConfig {
+    fn default() -> Self {
+        Self {
+            records: 1000,
+            max_len: 20,
+            damaged_percent: 40,
+            unknown_percent: 50,
+        }
+    }
+}
+
+pub fn day12(rng: &mut Rng, config: &SpringsConfig) -> String {
+    let mut out = String::new();
+    for _ in 0..config.records {
+        let len = rng.range(1..config.max_len as u64 + 1) as usize;
+        let mut damaged = (0..len)
+            .map(|_| rng.chance(config.damaged_percent))
+            .collect::<Vec<_>>();
+        if !damaged.contains(&true) {
+            damaged[rng.range(0..len as u64) as usize] = true;
+        }
+
+        let groups = damaged
+            .split(|d| !d)
+            .filter(|run| !run.is_empty())
+            .map(|run| run.len().to_string())
+            .collect::<Vec<_>>();
+        let springs = damaged
+            .iter()
+            .map(|&d| match (rng.chance(config.unknown_percent), d) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect::<String>();
+        writeln!(out, "{} {}", springs, groups.join(",")).unwrap();
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{day02, day04, day05, day06, day09, day12};
+
+    const SEEDS: std::ops::Range<u64> = 0..20;
+
+    #[test]
+    fn test_inputs_are_deterministic() {
+        assert_eq!(
+            day05(&mut Rng::new(3), &AlmanacConfig::default()),
+            day05(&mut Rng::new(3), &AlmanacConfig::default())
+        );
+    }
+
+    #[test]
+    fn test_day02_inputs_are_valid() {
+        for seed in SEEDS {
+            let input = day02(&mut Rng::new(seed), &GamesConfig::default());
+            let parsed = day02::input_generator(&input).unwrap();
+            assert_eq!(parsed.len(), GamesConfig::default().games);
+            day02::part1(&parsed);
+            day02::part2(&parsed);
+        }
+    }
+
+    #[test]
+    fn test_day04_inputs_are_valid() {
+        for seed in SEEDS {
+            let config = CardsConfig::default();
+            let input = day04(&mut Rng::new(seed), &config);
+            let parsed = day04::input_generator(&input).unwrap();
+            assert_eq!(parsed.len(), config.cards);
+            assert!(parsed.iter().enumerate().all(|(i, card)| {
+                card.winning_nums.len() == config.winning_numbers
+                    && card.user_nums.len() == config.user_numbers
+                    && i + card.get_matches().len() < config.cards
+            }));
+            day04::part1(&parsed);
+            day04::part2(&parsed);
+        }
+    }
+
+    #[test]
+    fn test_day05_inputs_are_valid() {
+        for seed in SEEDS {
+            let input = day05(&mut Rng::new(seed), &AlmanacConfig::default());
+            let parsed = day05::input_generator(&input).unwrap();
+            day05::part1(&parsed);
+            day05::part2(&parsed);
+        }
+    }
+
+    #[test]
+    fn test_day06_inputs_are_valid() {
+        // Keep the merged part 2 race short, the reference solver is linear in it.
+        let config = RacesConfig {
+            races: 3,
+            max_time: 30,
+        };
+        for seed in SEEDS {
+            let input = day06(&mut Rng::new(seed), &config);
+            let parsed = day06::input_generator(&input).unwrap();
+            assert_ne!(day06::part1(&parsed), 0u64);
+            day06::part2(&parsed);
+        }
+    }
+
+    #[test]
+    fn test_day09_inputs_are_valid() {
+        for seed in SEEDS {
+            let config = HistoriesConfig::default();
+            let input = day09(&mut Rng::new(seed), &config);
+            let parsed = day09::input_generator(&input).unwrap();
+            assert_eq!(parsed.len(), config.histories);
+            assert!(parsed.iter().all(|h| h.len() == config.length));
+            day09::part1(&parsed);
+            day09::part2(&parsed);
+        }
+    }
+
+    #[test]
+    fn test_day12_inputs_are_valid() {
+        for seed in SEEDS {
+            let config = SpringsConfig::default();
+            let input = day12(&mut Rng::new(seed), &config);
+            let parsed = day12::input_generator(&input).unwrap();
+            assert_eq!(parsed.len(), config.records);
+            assert!(parsed.iter().all(|r| r.arrangements() > 0));
+        }
+    }
+
+    #[test]
+    #[ignore = "stress test, run with --release -- --ignored"]
+    fn stress_day04() {
+        let config = CardsConfig {
+            cards: 1_000_000,
+            ..Default::default()
+        };
+        let input = day04(&mut Rng::new(0), &config);
+        let parsed = day04::input_generator(&input).unwrap();
+        day04::part2(&parsed);
+    }
+
+    #[test]
+    #[ignore = "stress test, run with --release -- --ignored"]
+    fn stress_day05_part2() {
+        let config = AlmanacConfig {
+            max_seed_range_len: 10_000_000,
+            ..Default::default()
+        };
+        let input = day05(&mut Rng::new(0), &config);
+        let parsed = day05::input_generator(&input).unwrap();
+        day05::part2(&parsed);
+    }
+}
@@ -0,0 +1,116 @@
+//! Every generator and solver of the crate, with the named variants of each
+//! part.
+//!
+//! Keep this in sync with the `#[aoc]` attributes: the unnamed variant of a
+//! part comes first, followed by its named variants.
+
+use aoc_common::{
+    day,
+    registry::{Day, Year},
+};
+
+pub static DAYS: &[Day] = &[
+    day!(1, day01, Vec<String>, [1 => part1, 2 => part2]),
+    day!(
+        2, day02, Vec<crate::day02::GameInfo>,
+        [1 => part1, 2 => part2],
+        stream [1 => part1_stream, 2 => part2_stream],
+    ),
+    day!(3, day03, crate::day03::Data, [1 => part1, 2 => part2]),
+    day!(
+        4, day04, Vec<crate::day04::CardInfo>,
+        [1 => part1, 2 => part2],
+        stream [1 => part1_stream, 2 => part2_stream],
+    ),
+    day!(5, day05, crate::day05::Data, [
+        1 => part1,
+        2 => part2,
+        2 as naive => part2_naive,
+    ]),
+    day!(6, day06, crate::day06::Data, [
+        1 => part1,
+        1 as naive => part1_naive,
+        2 => part2,
+        2 as naive => part2_naive,
+    ]),
+    day!(7, day07, Vec<crate::day07::Hand>, [1 => part1, 2 => part2]),
+    day!(8, day08, crate::day08::Data, [1 => part1, 2 => part2]),
+    day!(9, day09, Vec<Vec<i64>>, [
+        1 => part1,
+        1 as lagrange => part1_lagrange,
+        2 => part2,
+        2 as lagrange => part2_lagrange,
+    ]),
+    day!(10, day10, crate::day10::Data, [
+        1 => part1,
+        2 => part2,
+        2 as shoelace => part2_shoelace,
+    ]),
+    day!(11, day11, crate::day11::Data, [1 => part1, 2 => part2]),
+    day!(12, day12, Vec<crate::day12::Record>, [1 => part1, 2 => part2]),
+    day!(14, day14, crate::day14::Data, [1 => part1, 2 => part2], visualise visualise),
+];
+
+pub static YEAR: Year = Year {
+    year: 2023,
+    days: DAYS,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_days_are_sorted() {
+        assert!(DAYS.windows(2).all(|w| w[0].day < w[1].day));
+    }
+
+    #[test]
+    fn test_default_variant_first() {
+        for day in DAYS {
+            for part in day.parts() {
+                let names = day.variants(part).map(|s| s.name).collect::<Vec<_>>();
+                assert_eq!(names[0], None, "Day {} part {}", day.day, part);
+                assert!(names[1..].iter().all(|n| n.is_some()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_streams_have_solvers() {
+        for day in DAYS {
+            for stream in day.streams {
+                assert!(day.parts().contains(&stream.part), "Day {}", day.day);
+            }
+        }
+    }
+
+    #[test]
+    fn test_variants_agree_on_examples() {
+        for day in DAYS {
+            let example = std::fs::read_to_string(format!(
+                "{}/fuzz/corpus/day{:02}/example",
+                env!("CARGO_MANIFEST_DIR"),
+                day.day
+            ))
+            .unwrap();
+            let parsed = (day.parse)(&example).unwrap();
+            for part in day.parts() {
+                let mut answers = day
+                    .variants(part)
+                    .map(|s| (s.solve)(parsed.as_ref()))
+                    .collect::<Vec<_>>();
+                if let Some(stream) = day.stream(part) {
+                    answers.push((stream.solve)(&mut example.as_bytes()).unwrap());
+                }
+                assert!(
+                    answers.iter().all(|a| *a == answers[0]),
+                    "Day {} part {}: {:?}",
+                    day.day,
+                    part,
+                    answers
+                );
+            }
+        }
+    }
+}
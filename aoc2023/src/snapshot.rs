@@ -0,0 +1,16 @@
+//! Real puzzle inputs for the parser snapshot tests.
+
+use std::{fs, path::Path};
+
+/// The input of `day` from `input/2023`, or `None` when it is not checked out
+/// so that the snapshot is skipped rather than failed.
+pub fn real_input(day: u32) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("input/2023/day{}.txt", day));
+    match fs::read_to_string(&path) {
+        Ok(input) => Some(input),
+        Err(_) => {
+            eprintln!("Skipping snapshot, {} is missing", path.display());
+            None
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! The answer to a puzzle part: an integer of any width, or a string.
+
+use std::fmt::{self, Display};
+
+use anyhow::anyhow;
+
+/// Integers are kept in a canonical form, non-negative values are always
+/// `Unsigned`, so answers compare equal whichever integer type produced them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),+) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Unsigned(n as u128)
+            }
+        }
+    )+};
+}
+
+macro_rules! from_signed {
+    ($($t:ty),+) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match u128::try_from(n) {
+                    Ok(n) => Answer::Unsigned(n),
+                    Err(_) => Answer::Signed(n as i128),
+                }
+            }
+        }
+    )+};
+}
+
+// Widening casts only, none of these can truncate.
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+macro_rules! try_into_integer {
+    ($($t:ty),+) => {$(
+        impl TryFrom<&Answer> for $t {
+            type Error = anyhow::Error;
+
+            fn try_from(answer: &Answer) -> Result<Self, Self::Error> {
+                let converted = match answer {
+                    Answer::Unsigned(n) => <$t>::try_from(*n).ok(),
+                    Answer::Signed(n) => <$t>::try_from(*n).ok(),
+                    Answer::Text(_) => None,
+                };
+                converted.ok_or_else(|| {
+                    anyhow!("Answer {} does not fit in {}", answer, stringify!($t))
+                })
+            }
+        }
+
+        impl PartialEq<$t> for Answer {
+            fn eq(&self, other: &$t) -> bool {
+                <$t>::try_from(self).is_ok_and(|n| n == *other)
+            }
+        }
+    )+};
+}
+
+try_into_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Text(s) if s == other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_canonical_integers() {
+        assert_eq!(Answer::from(42u8), Answer::from(42i64));
+        assert_eq!(Answer::from(-3i32), Answer::Signed(-3));
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+        assert_eq!(Answer::from(i128::MIN), Answer::Signed(i128::MIN));
+        assert_eq!(Answer::from(7u32), 7u64);
+        assert_eq!(Answer::from("LR"), "LR");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-15i64).to_string(), "-15");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("JKL").to_string(), "JKL");
+    }
+
+    #[test]
+    fn test_conversions_fail_loudly() {
+        assert_eq!(u32::try_from(&Answer::from(71503u64)).unwrap(), 71503);
+        assert_eq!(i64::try_from(&Answer::from(-2i8)).unwrap(), -2);
+        assert!(u32::try_from(&Answer::from(u32::MAX as u64 + 1)).is_err());
+        assert!(u64::try_from(&Answer::from(-1i64)).is_err());
+        assert!(u64::try_from(&Answer::from("12")).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Differential testing of an optimised solver against its reference
+//! implementation on inputs from [`crate::random`].
+
+use std::fmt::{self, Debug, Display};
+
+use crate::random::Rng;
+
+/// An input on which the two implementations disagree.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Divergence<A> {
+    pub seed: u64,
+    pub scale: usize,
+    pub input: String,
+    pub reference: A,
+    pub optimised: A,
+}
+
+impl<A: Debug> Display for Divergence<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "reference gave {:?} but optimised gave {:?} (seed {}, scale {}) on input:",
+            self.reference, self.optimised, self.seed, self.scale
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Runs both implementations on a generated input for every seed at every
+/// scale, smallest scale first, and returns the smallest diverging input.
+///
+/// The first diverging input found is shrunk further by dropping lines while
+/// the implementations still disagree. Both implementations return `None` for
+/// inputs they cannot parse, which are never reported.
+pub fn find_divergence<A, G, R, O>(
+    scales: impl IntoIterator<Item = usize>,
+    seeds: std::ops::Range<u64>,
+    generate: G,
+    reference: R,
+    optimised: O,
+) -> Option<Divergence<A>>
+where
+    A: PartialEq,
+    G: Fn(&mut Rng, usize) -> String,
+    R: Fn(&str) -> Option<A>,
+    O: Fn(&str) -> Option<A>,
+{
+    let diverges = |input: &str| match (reference(input), optimised(input)) {
+        (Some(r), Some(o)) if r != o => Some((r, o)),
+        _ => None,
+    };
+
+    for scale in scales {
+        for seed in seeds.clone() {
+            let input = generate(&mut Rng::new(seed), scale);
+            if diverges(&input).is_none() {
+                continue;
+            }
+
+            let input = shrink(input, |i| diverges(i).is_some());
+            let (reference, optimised) = diverges(&input).unwrap();
+            return Some(Divergence {
+                seed,
+                scale,
+                input,
+                reference,
+                optimised,
+            });
+        }
+    }
+
+    None
+}
+
+/// Removes single lines from `input` for as long as `failing` keeps holding.
+fn shrink(mut input: String, failing: impl Fn(&str) -> bool) -> String {
+    loop {
+        let lines = input.split_inclusive('\n').collect::<Vec<_>>();
+        let smaller = (0..lines.len())
+            .map(|skip| {
+                lines
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != skip)
+                    .map(|(_, l)| *l)
+                    .collect::<String>()
+            })
+            .find(|candidate| failing(candidate));
+
+        match smaller {
+            Some(smaller) => input = smaller,
+            None => return input,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn numbers(rng: &mut Rng, scale: usize) -> String {
+        (0..scale)
+            .map(|_| format!("{}\n", rng.range(0..100)))
+            .collect()
+    }
+
+    fn sum(input: &str) -> Option<u64> {
+        input.lines().map(|l| l.parse::<u64>().ok()).sum()
+    }
+
+    #[test]
+    fn test_agreeing_implementations() {
+        let found = find_divergence(1..10, 0..10, numbers, sum, |input| {
+            Some(input.lines().map(|l| l.parse::<u64>().unwrap()).sum())
+        });
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn test_smallest_divergence() {
+        // Wrong as soon as a single number is at least 90.
+        let capped = |input: &str| {
+            sum(input).map(|_| {
+                input
+                    .lines()
+                    .map(|l| l.parse::<u64>().unwrap().min(89))
+                    .sum()
+            })
+        };
+        let found = find_divergence(1..10, 0..10, numbers, sum, capped).unwrap();
+
+        assert_eq!(found.input.lines().count(), 1);
+        assert!(found.reference >= 90);
+    }
+}
@@ -0,0 +1,52 @@
+//! Deterministic replay of a fuzz corpus, so every input generator is
+//! exercised by `cargo test` without cargo-fuzz or nightly.
+
+use std::{fs, iter, panic, path::Path};
+
+const REPLACEMENTS: &[&str] = &["", " ", "\n", ":", "|", ";", ",", "-", "x", "99999999999"];
+
+/// Runs `generator` on every corpus file in `dir` and on a fixed set of
+/// mutations of each one, failing if any of them panics.
+pub fn check_corpus<T>(dir: &Path, generator: fn(&str) -> T) {
+    let mut seeds = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Could not read corpus {}: {}", dir.display(), e))
+        .map(|entry| entry.expect("Could not read corpus entry").path())
+        .collect::<Vec<_>>();
+    seeds.sort();
+    assert!(!seeds.is_empty(), "Corpus {} is empty", dir.display());
+
+    let mut failures = Vec::new();
+    for seed in seeds {
+        let seed = fs::read_to_string(&seed).expect("Could not read corpus file");
+        for input in mutations(&seed) {
+            if panic::catch_unwind(|| generator(&input)).is_err() {
+                failures.push(input);
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} generator panicked on {} inputs, first one: {:?}",
+        dir.display(),
+        failures.len(),
+        failures[0]
+    );
+}
+
+/// The seed itself, every prefix of it and every single-character replacement
+/// from `REPLACEMENTS`.
+fn mutations(seed: &str) -> Vec<String> {
+    let mut inputs = vec![seed.to_string()];
+    for (i, c) in seed.char_indices() {
+        inputs.push(seed[..i].to_string());
+        inputs.extend(
+            REPLACEMENTS
+                .iter()
+                .map(|r| [&seed[..i], r, &seed[i + c.len_utf8()..]].concat()),
+        );
+    }
+    inputs.extend(iter::once(format!("{}{}", seed, seed)));
+
+    inputs
+}
@@ -0,0 +1,354 @@
+//! Rectangular grids of cells, as in most map and maze puzzles.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use anyhow::{bail, Context};
+
+use crate::parse;
+
+/// A position in a grid, `x` growing to the right and `y` downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A horizontal run of cells in row `y`, from `start` up to but excluding
+/// `end`, e.g. the digits of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub y: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.y == self.y && (self.start..self.end).contains(&pos.x)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (self.start..self.end).map(|x| Pos::new(x, self.y))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+/// How a cell is drawn, in dumps, snapshots and the terminal renderer.
+pub trait Glyph {
+    fn glyph(&self) -> char;
+
+    fn colour(&self) -> Colour {
+        Colour::Default
+    }
+}
+
+impl Glyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+/// Terminal colours, see [`crate::visual`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Default,
+    Grey,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character, one row per line of the normalised
+    /// input. Every row must have the same width.
+    pub fn parse(input: &str, cell: impl Fn(char) -> anyhow::Result<T>) -> anyhow::Result<Self> {
+        let input = parse::normalise(input);
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        for (y, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+            match width {
+                None if row_width == 0 => bail!("Blank line in grid"),
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    bail!("Row {} is {} wide, expected {}", y, row_width, w)
+                }
+                Some(_) => {}
+            }
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).with_context(|| format!("Invalid cell at {}, {}", x, y))?);
+            }
+        }
+
+        let width = width.context("Empty grid")?;
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// The neighbouring position in `direction`, if it is inside the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        let next = match direction {
+            Direction::North => Pos::new(pos.x, pos.y.checked_sub(1)?),
+            Direction::East => Pos::new(pos.x + 1, pos.y),
+            Direction::South => Pos::new(pos.x, pos.y + 1),
+            Direction::West => Pos::new(pos.x.checked_sub(1)?, pos.y),
+        };
+        self.index_of(next).map(|_| next)
+    }
+
+    /// The up to eight positions around `pos`, diagonals included.
+    pub fn around(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let xs = pos.x.saturating_sub(1)..=(pos.x + 1).min(self.width - 1);
+        let ys = pos.y.saturating_sub(1)..=(pos.y + 1).min(self.height - 1);
+        ys.flat_map(move |y| xs.clone().map(move |x| Pos::new(x, y)))
+            .filter(move |&p| p != pos)
+    }
+
+    /// Every maximal run of cells matching `matches` within a row, row by row.
+    pub fn spans(&self, matches: impl Fn(&T) -> bool) -> Vec<Span> {
+        let mut spans = Vec::new();
+        for (y, row) in self.rows().enumerate() {
+            let mut start = None;
+            for (x, cell) in row.iter().enumerate() {
+                match (matches(cell), start) {
+                    (true, None) => start = Some(x),
+                    (false, Some(s)) => {
+                        spans.push(Span {
+                            y,
+                            start: s,
+                            end: x,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(start) = start {
+                spans.push(Span {
+                    y,
+                    start,
+                    end: self.width,
+                });
+            }
+        }
+        spans
+    }
+
+    /// The positions touching `span`, diagonals included, inside the grid.
+    pub fn around_span(&self, span: &Span) -> impl Iterator<Item = Pos> + '_ {
+        let xs = span.start.saturating_sub(1)..(span.end + 1).min(self.width);
+        let ys = span.y.saturating_sub(1)..=(span.y + 1).min(self.height - 1);
+        let span = *span;
+        ys.flat_map(move |y| xs.clone().map(move |x| Pos::new(x, y)))
+            .filter(move |&p| !span.contains(p))
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        (pos.x < self.width && pos.y < self.height).then(|| pos.y * self.width + pos.x)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T: Glyph> Grid<T> {
+    /// The grid as text, one line per row.
+    pub fn to_text(&self) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(Glyph::glyph));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Rows are shown as strings, which keeps snapshots of large grids readable.
+impl<T: Glyph> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .rows()
+            .map(|row| row.iter().map(Glyph::glyph).collect::<String>())
+            .collect::<Vec<_>>();
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("rows", &rows)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("\r\nab\r\ncd\r\nef\r\n", Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Pos::new(1, 2)], 'f');
+        assert_eq!(grid.to_text(), "ab\ncd\nef\n");
+        assert!(Grid::parse("ab\nc", Ok).is_err());
+        assert!(Grid::parse("", Ok).is_err());
+        assert!(Grid::parse("ab\n\nab", Ok).is_err());
+        assert!(Grid::parse("a?", |c| match c {
+            'a' => Ok(c),
+            _ => bail!("Unknown cell {:?}", c),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.step(Pos::new(0, 0), Direction::East),
+            Some(Pos::new(1, 0))
+        );
+        assert_eq!(grid.step(Pos::new(0, 0), Direction::North), None);
+        assert_eq!(grid.step(Pos::new(1, 0), Direction::East), None);
+        assert_eq!(grid.step(Pos::new(0, 2), Direction::South), None);
+    }
+
+    #[test]
+    fn test_spans() {
+        let grid = Grid::parse("12..3\n.45..\n....6", Ok).unwrap();
+        let spans = grid.spans(char::is_ascii_digit);
+
+        assert_eq!(
+            spans,
+            vec![
+                Span {
+                    y: 0,
+                    start: 0,
+                    end: 2
+                },
+                Span {
+                    y: 0,
+                    start: 4,
+                    end: 5
+                },
+                Span {
+                    y: 1,
+                    start: 1,
+                    end: 3
+                },
+                Span {
+                    y: 2,
+                    start: 4,
+                    end: 5
+                },
+            ]
+        );
+        assert_eq!(grid.around_span(&spans[0]).count(), 4);
+        assert_eq!(grid.around_span(&spans[2]).count(), 10);
+        assert_eq!(grid.around_span(&spans[3]).count(), 3);
+        assert!(grid.around_span(&spans[2]).all(|p| !spans[2].contains(p)));
+    }
+
+    #[test]
+    fn test_around() {
+        let grid = grid();
+
+        assert_eq!(grid.around(Pos::new(0, 0)).count(), 3);
+        assert_eq!(grid.around(Pos::new(1, 1)).count(), 5);
+        assert!(!grid.around(Pos::new(1, 1)).any(|p| p == Pos::new(1, 1)));
+    }
+}
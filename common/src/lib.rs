@@ -0,0 +1,11 @@
+//! Helpers shared by the solutions of every year.
+
+pub mod answer;
+pub mod differential;
+pub mod fuzz;
+pub mod grid;
+pub mod math;
+pub mod parse;
+pub mod random;
+pub mod registry;
+pub mod visual;
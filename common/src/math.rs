@@ -0,0 +1,102 @@
+//! Number theory for puzzles that ask when several cycles line up.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` if it does not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Combines `t ≡ r1 (mod m1)` and `t ≡ r2 (mod m2)` into a single congruence
+/// `t ≡ r (mod lcm(m1, m2))`. The moduli need not be coprime; `None` when the
+/// two have no common solution.
+///
+/// Panics if a modulus is zero or the combined modulus overflows a `u64`.
+pub fn crt((r1, m1): (u64, u64), (r2, m2): (u64, u64)) -> Option<(u64, u64)> {
+    assert!(m1 > 0 && m2 > 0, "Zero modulus");
+    let (r1, r2) = (r1 % m1, r2 % m2);
+    let g = gcd(m1, m2);
+    let diff = i128::from(r2) - i128::from(r1);
+    if diff % i128::from(g) != 0 {
+        return None;
+    }
+
+    // Solve m1 * k ≡ r2 - r1 (mod m2), dividing through by the gcd first.
+    let m = m2 / g;
+    let inverse = inverse(m1 / g % m, m);
+    let diff = u128::try_from((diff / i128::from(g)).rem_euclid(i128::from(m)))
+        .expect("Remainder is never negative");
+    let k = diff * u128::from(inverse) % u128::from(m);
+
+    let modulus = lcm(m1, m2).expect("Combined modulus overflowed");
+    let r = (u128::from(r1) + u128::from(m1) * k) % u128::from(modulus);
+    Some((u64::try_from(r).expect("Below the modulus"), modulus))
+}
+
+/// Inverse of `a` modulo `m`, for coprime `a` and `m`.
+fn inverse(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (i128::from(a), i128::from(m));
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    debug_assert!(old_r == 1 || m == 1, "{} has no inverse modulo {}", a, m);
+    u64::try_from(old_s.rem_euclid(i128::from(m))).expect("Inverse is below the modulus")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt((2, 3), (1, 4)), Some((5, 12)));
+        // Non-coprime moduli, with and without a solution.
+        assert_eq!(crt((2, 4), (4, 6)), Some((10, 12)));
+        assert_eq!(crt((1, 4), (2, 6)), None);
+        assert_eq!(crt((0, 1), (5, 7)), Some((5, 7)));
+    }
+
+    #[test]
+    fn test_crt_brute_force() {
+        for m1 in 1..12 {
+            for m2 in 1..12 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let l = lcm(m1, m2).unwrap();
+                        let expected = (0..l).find(|t| t % m1 == r1 && t % m2 == r2);
+                        assert_eq!(crt((r1, m1), (r2, m2)), expected.map(|t| (t, l)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt_large() {
+        let (p, q) = (4_294_967_291, 4_294_967_279);
+        let (r, m) = crt((5, p), (7, q)).unwrap();
+
+        assert_eq!(m, p * q);
+        assert_eq!((r % p, r % q), (5, 7));
+    }
+}
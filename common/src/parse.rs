@@ -0,0 +1,122 @@
+//! Parsing helpers shared by the generators of every year.
+
+use std::{borrow::Cow, error::Error, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+
+/// Normalises raw puzzle input before parsing: CRLF and lone CR become LF,
+/// tabs become spaces, every line is trimmed and leading and trailing blank
+/// lines are dropped. Blank lines between sections are kept, collapsed to one.
+pub fn normalise(input: &str) -> String {
+    let input = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut out = String::with_capacity(input.len());
+    let mut blank = false;
+    for line in input
+        .split('\n')
+        .map(normalise_line)
+        .skip_while(|l| l.is_empty())
+    {
+        if line.is_empty() {
+            blank = true;
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        out.push_str(&line);
+        blank = false;
+    }
+
+    out
+}
+
+/// Normalises a single line as [`normalise`] does: tabs become spaces and
+/// surrounding whitespace, including a trailing CR, is trimmed.
+pub fn normalise_line(line: &str) -> Cow<'_, str> {
+    let line = line.trim();
+    match line.contains('\t') {
+        true => Cow::Owned(line.replace('\t', " ")),
+        false => Cow::Borrowed(line),
+    }
+}
+
+/// Calls `f` on every non-blank line of `reader`, normalised by
+/// [`normalise_line`]. One buffer is reused for every line, so memory stays
+/// constant however long the input is. Lone CR line endings are not split.
+pub fn for_each_line<R: BufRead>(
+    mut reader: R,
+    mut f: impl FnMut(&str) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let mut buf = String::new();
+    let mut number = 0;
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf).context("Could not read input")? == 0 {
+            return Ok(());
+        }
+        number += 1;
+
+        let line = normalise_line(&buf);
+        if !line.is_empty() {
+            f(&line).with_context(|| format!("Could not process line {}", number))?;
+        }
+    }
+}
+
+/// Parses numbers separated by any amount of whitespace.
+pub fn numbers<T>(s: &str) -> anyhow::Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    s.split_whitespace()
+        .map(|n| {
+            n.parse::<T>()
+                .with_context(|| format!("Could not parse number {:?}", n))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(normalise("\n\na 1\nb 2\n"), "a 1\nb 2");
+        assert_eq!(normalise("a 1\r\nb 2\r\n"), "a 1\nb 2");
+        assert_eq!(normalise("a\tb  \n\n\n  c\r\rd"), "a b\n\nc\n\nd");
+        assert_eq!(normalise("x:\r\n1\r\n\r\ny:\r\n2\r\n"), "x:\n1\n\ny:\n2");
+        assert_eq!(normalise(" \r\n\t\n"), "");
+    }
+
+    #[test]
+    fn test_for_each_line() {
+        let mut lines = Vec::new();
+        for_each_line("\r\na\tb \r\n\n c\n".as_bytes(), |l| {
+            lines.push(l.to_string());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(lines, vec!["a b", "c"]);
+
+        let error = for_each_line("1\n2\nx\n".as_bytes(), |l| {
+            l.parse::<u32>()?;
+            Ok(())
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "Could not process line 3");
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            numbers::<u32>(" 41 48  6\t17 ").unwrap(),
+            vec![41, 48, 6, 17]
+        );
+        assert_eq!(numbers::<i64>("-3 0 15").unwrap(), vec![-3, 0, 15]);
+        assert!(numbers::<u32>("").unwrap().is_empty());
+        assert!(numbers::<u32>("1 x 3").is_err());
+    }
+}
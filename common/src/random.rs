@@ -0,0 +1,91 @@
+//! Seeded pseudo random numbers for generating puzzle inputs.
+
+use std::{collections::HashSet, ops::Range};
+
+/// Small SplitMix64 generator. Not suitable for anything but test data, but
+/// stable across platforms and crate versions.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "Empty range {:?}", range);
+        let span = range.end - range.start;
+        range.start + ((self.next_u64() as u128 * span as u128) >> 64) as u64
+    }
+
+    /// True with the given probability in percent.
+    pub fn chance(&mut self, percent: u32) -> bool {
+        self.range(0..100) < percent as u64
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0..i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// `count` distinct values from `range`, in random order.
+    pub fn distinct(&mut self, range: Range<u64>, count: usize) -> Vec<u64> {
+        let span = range.end.saturating_sub(range.start);
+        assert!(
+            count as u64 <= span,
+            "Cannot pick {} distinct values",
+            count
+        );
+
+        if count as u64 * 2 > span {
+            let mut values = range.collect::<Vec<_>>();
+            self.shuffle(&mut values);
+            values.truncate(count);
+            return values;
+        }
+
+        let mut seen = HashSet::new();
+        let mut values = Vec::with_capacity(count);
+        while values.len() < count {
+            let value = self.range(range.clone());
+            if seen.insert(value) {
+                values.push(value);
+            }
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let a = (0..10).map(|_| Rng::new(7).next_u64()).collect::<Vec<_>>();
+        assert!(a.iter().all(|v| *v == a[0]));
+    }
+
+    #[test]
+    fn test_distinct() {
+        let mut rng = Rng::new(0);
+        for count in [0, 3, 10] {
+            let mut values = rng.distinct(5..15, count);
+            assert!(values.iter().all(|v| (5..15).contains(v)));
+            values.sort_unstable();
+            values.dedup();
+            assert_eq!(values.len(), count);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Type-erased generators and solvers, so the days of every year can be
+//! listed and run without the cargo-aoc glue.
+
+use std::{any::Any, io::BufRead};
+
+use crate::{answer::Answer, visual::Renderer};
+
+/// Renders a day's solution on its parsed input, see [`Day::visualise`].
+pub type Visualise = fn(&dyn Any, &mut Renderer) -> anyhow::Result<()>;
+
+/// Every day registered for one year.
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn find(&self, day: u32) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+/// A day's generator and all the solvers registered for it.
+pub struct Day {
+    pub day: u32,
+    pub parse: fn(&str) -> anyhow::Result<Box<dyn Any>>,
+    pub solvers: &'static [Solver],
+    /// Parts that can also be solved straight from a reader, line by line.
+    pub streams: &'static [Stream],
+    /// Renders the solution at work, frame by frame, on the parsed input.
+    pub visualise: Option<Visualise>,
+}
+
+/// One implementation of a part. `name` is `None` for the default one.
+pub struct Solver {
+    pub part: u32,
+    pub name: Option<&'static str>,
+    pub solve: fn(&dyn Any) -> Answer,
+}
+
+/// Solves a part without loading the input, for inputs too large for memory.
+pub struct Stream {
+    pub part: u32,
+    pub solve: fn(&mut dyn BufRead) -> anyhow::Result<Answer>,
+}
+
+impl Solver {
+    pub fn label(&self) -> String {
+        match self.name {
+            Some(name) => format!("Part {} - {}", self.part, name),
+            None => format!("Part {}", self.part),
+        }
+    }
+}
+
+impl Day {
+    pub fn parts(&self) -> Vec<u32> {
+        let mut parts = self.solvers.iter().map(|s| s.part).collect::<Vec<_>>();
+        parts.dedup();
+        parts
+    }
+
+    pub fn variants(&self, part: u32) -> impl Iterator<Item = &Solver> {
+        self.solvers.iter().filter(move |s| s.part == part)
+    }
+
+    pub fn stream(&self, part: u32) -> Option<&Stream> {
+        self.streams.iter().find(|s| s.part == part)
+    }
+}
+
+/// Builds a [`Day`] from a module of the calling crate that follows the
+/// `input_generator`/`partN` layout, e.g.
+/// `day!(5, day05, day05::Data, [1 => part1, 2 => part2, 2 as naive => part2_naive])`.
+/// Streaming parts follow in a `stream` list, e.g.
+/// `day!(4, day04, Vec<day04::CardInfo>, [1 => part1], stream [1 => part1_stream])`,
+/// and a visualisation last, e.g. `day!(14, day14, day14::Data, [1 => part1], visualise visualise)`.
+#[macro_export]
+// `crate` is deliberately the calling crate, where the day modules live.
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! day {
+    (
+        $day:literal, $module:ident, $parsed:ty,
+        [$($part:literal $(as $name:ident)? => $func:ident),+ $(,)?]
+        $(, stream [$($stream_part:literal => $stream_func:ident),+ $(,)?])?
+        $(, visualise $visualise:ident)? $(,)?
+    ) => {
+        $crate::registry::Day {
+            day: $day,
+            parse: |input| Ok(Box::new(crate::$module::input_generator(input)?)),
+            solvers: &[$($crate::registry::Solver {
+                part: $part,
+                name: $crate::day!(@name $($name)?),
+                solve: |parsed| {
+                    let parsed = parsed
+                        .downcast_ref::<$parsed>()
+                        .expect(concat!("Parsed input is not a ", stringify!($parsed)));
+                    crate::$module::$func(parsed)
+                },
+            }),+],
+            streams: &[$($($crate::registry::Stream {
+                part: $stream_part,
+                solve: |reader| crate::$module::$stream_func(reader),
+            }),+)?],
+            visualise: $crate::day!(@visualise $module, $parsed $(, $visualise)?),
+        }
+    };
+    (@visualise $module:ident, $parsed:ty) => { None };
+    (@visualise $module:ident, $parsed:ty, $visualise:ident) => {
+        Some(|parsed, renderer| {
+            let parsed = parsed
+                .downcast_ref::<$parsed>()
+                .expect(concat!("Parsed input is not a ", stringify!($parsed)));
+            crate::$module::$visualise(parsed, renderer)
+        })
+    };
+    (@name) => { None };
+    (@name $name:ident) => { Some(stringify!($name)) };
+}
@@ -0,0 +1,191 @@
+//! Frame by frame rendering of grids, to watch what a solution does.
+//!
+//! Frames go to a terminal, redrawn in place with ANSI colours and the cells
+//! that changed since the previous frame highlighted, and can also be dumped
+//! as plain text files, one per frame, to check them in tests.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use crate::grid::{Colour, Glyph, Grid};
+
+#[derive(Debug, Clone, Default)]
+pub struct RenderConfig {
+    /// Frames per second on the terminal, as fast as possible when `None`.
+    pub fps: Option<u32>,
+    /// Redraw in place with colours, rather than printing plain frames one
+    /// after the other.
+    pub ansi: bool,
+    /// Directory to write every frame to, as `frame_00000.txt` and so on.
+    pub dump_dir: Option<PathBuf>,
+}
+
+pub struct Renderer {
+    config: RenderConfig,
+    out: Option<Box<dyn Write>>,
+    previous: Option<Vec<char>>,
+    frames: usize,
+}
+
+impl Renderer {
+    /// Renders to `out`, or only dumps frames when `out` is `None`.
+    pub fn new(config: RenderConfig, out: Option<Box<dyn Write>>) -> io::Result<Self> {
+        if let Some(dir) = &config.dump_dir {
+            fs::create_dir_all(dir)?;
+        }
+        Ok(Self {
+            config,
+            out,
+            previous: None,
+            frames: 0,
+        })
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Draws one state of `grid`, under a one line `caption`.
+    pub fn frame<T: Glyph>(&mut self, grid: &Grid<T>, caption: &str) -> io::Result<()> {
+        let glyphs = grid.cells().iter().map(Glyph::glyph).collect::<Vec<_>>();
+
+        if let Some(dir) = &self.config.dump_dir {
+            let path = dir.join(format!("frame_{:05}.txt", self.frames));
+            fs::write(path, format!("{}\n{}", caption, grid.to_text()))?;
+        }
+
+        if let Some(out) = &mut self.out {
+            let text = match self.config.ansi {
+                true => ansi(grid, caption, self.previous.as_deref()),
+                false => format!("{}\n{}\n", caption, grid.to_text()),
+            };
+            out.write_all(text.as_bytes())?;
+            out.flush()?;
+            if let Some(fps) = self.config.fps.filter(|&fps| fps > 0) {
+                thread::sleep(Duration::from_secs(1) / fps);
+            }
+        }
+
+        self.previous = Some(glyphs);
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+fn colour_code(colour: Colour) -> &'static str {
+    match colour {
+        Colour::Default => "",
+        Colour::Grey => "\x1b[90m",
+        Colour::Red => "\x1b[31m",
+        Colour::Green => "\x1b[32m",
+        Colour::Yellow => "\x1b[33m",
+        Colour::Blue => "\x1b[34m",
+        Colour::Magenta => "\x1b[35m",
+        Colour::Cyan => "\x1b[36m",
+    }
+}
+
+/// The frame with the cursor moved home first, so it overwrites the last one.
+/// Cells that differ from `previous` are drawn in reverse video.
+fn ansi<T: Glyph>(grid: &Grid<T>, caption: &str, previous: Option<&[char]>) -> String {
+    let mut text = String::from("\x1b[H");
+    if previous.is_none() {
+        text.push_str("\x1b[2J\x1b[H");
+    }
+    text.push_str(caption);
+    text.push_str("\x1b[K\n");
+
+    for (y, row) in grid.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            let glyph = cell.glyph();
+            let changed = previous.is_some_and(|p| p[y * grid.width() + x] != glyph);
+            text.push_str(colour_code(cell.colour()));
+            if changed {
+                text.push_str("\x1b[7m");
+            }
+            text.push(glyph);
+            text.push_str(RESET);
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    /// Collects everything written, shared with the test after the renderer
+    /// took ownership.
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_dump_frames() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("aoc-visual-{}", nanos));
+        let config = RenderConfig {
+            dump_dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let mut renderer = Renderer::new(config, None).unwrap();
+
+        let mut grid = Grid::parse("ab\ncd", Ok).unwrap();
+        renderer.frame(&grid, "first").unwrap();
+        grid[crate::grid::Pos::new(1, 1)] = 'x';
+        renderer.frame(&grid, "second").unwrap();
+
+        assert_eq!(renderer.frames(), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("frame_00001.txt")).unwrap(),
+            "second\nab\ncx\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_ansi_highlights_changes() {
+        let out = Shared::default();
+        let config = RenderConfig {
+            ansi: true,
+            ..Default::default()
+        };
+        let mut renderer = Renderer::new(config, Some(Box::new(out.clone()))).unwrap();
+
+        let mut grid = Grid::parse("ab", Ok).unwrap();
+        renderer.frame(&grid, "").unwrap();
+        assert!(!String::from_utf8_lossy(&out.0.borrow()).contains("\x1b[7m"));
+
+        out.0.borrow_mut().clear();
+        grid[crate::grid::Pos::new(0, 0)] = 'z';
+        renderer.frame(&grid, "").unwrap();
+        let text = String::from_utf8_lossy(&out.0.borrow()).to_string();
+        assert!(text.contains("\x1b[7mz"));
+        assert!(!text.contains("\x1b[7mb"));
+    }
+}
@@ -0,0 +1,40 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Solves one part of a puzzle on the `input_len` bytes of UTF-8 at `input`.
+ *
+ * Returns the answer as a NUL-terminated string to be released with
+ * [`aoc_free_string`], or null on failure, see [`aoc_last_error`].
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes, or be null when
+ * `input_len` is 0.
+ */
+char *aoc_solve(uint32_t year, uint32_t day, uint32_t part, const uint8_t *input, size_t input_len);
+
+/**
+ * Releases an answer returned by [`aoc_solve`]. Null is ignored.
+ *
+ * # Safety
+ *
+ * `answer` must come from [`aoc_solve`] and not have been freed already.
+ */
+void aoc_free_string(char *answer);
+
+/**
+ * The message of the last failure on the calling thread, or null if there
+ * was none. It stays valid until the next failing call on the same thread.
+ */
+const char *aoc_last_error(void);
+
+#endif /* AOC_H */
@@ -0,0 +1,85 @@
+//! C API over [`advent_of_code::solve`], built as `libaoc_ffi`. The header is
+//! `include/aoc.h`, regenerated with cbindgen by the `header` test.
+//!
+//! Answers are returned as strings owned by the library and must be released
+//! with [`aoc_free_string`]. Failures return null and leave a message for
+//! [`aoc_last_error`].
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CString},
+    ptr, slice,
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    // Interior NULs would cut the message short, so they are dropped.
+    let message = CString::new(message.replace('\0', "")).expect("NULs were removed");
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+/// Solves one part of a puzzle on the `input_len` bytes of UTF-8 at `input`.
+///
+/// Returns the answer as a NUL-terminated string to be released with
+/// [`aoc_free_string`], or null on failure, see [`aoc_last_error`].
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, or be null when
+/// `input_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: *const u8,
+    input_len: usize,
+) -> *mut c_char {
+    let bytes = match input.is_null() {
+        true if input_len == 0 => &[][..],
+        true => {
+            set_last_error("Input is null".to_string());
+            return ptr::null_mut();
+        }
+        false => slice::from_raw_parts(input, input_len),
+    };
+
+    let answer = std::str::from_utf8(bytes)
+        .map_err(|e| anyhow::anyhow!("Input is not UTF-8: {}", e))
+        .and_then(|input| advent_of_code::solve(year, day, part, input));
+    match answer {
+        Ok(answer) => match CString::new(answer.to_string()) {
+            Ok(answer) => answer.into_raw(),
+            Err(_) => {
+                set_last_error("Answer contains a NUL byte".to_string());
+                ptr::null_mut()
+            }
+        },
+        Err(e) => {
+            set_last_error(format!("{:#}", e));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases an answer returned by [`aoc_solve`]. Null is ignored.
+///
+/// # Safety
+///
+/// `answer` must come from [`aoc_solve`] and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(answer: *mut c_char) {
+    if !answer.is_null() {
+        drop(CString::from_raw(answer));
+    }
+}
+
+/// The message of the last failure on the calling thread, or null if there
+/// was none. It stays valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn aoc_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
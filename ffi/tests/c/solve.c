@@ -0,0 +1,54 @@
+/* Exercises the C API the way an embedding program would. */
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static const char EXAMPLE[] = "Time:      7  15   30\r\nDistance:  9  40  200\r\n";
+
+static int failures = 0;
+
+static void expect_answer(uint32_t day, uint32_t part, const char *input, size_t len,
+                          const char *expected) {
+    char *answer = aoc_solve(2023, day, part, (const uint8_t *)input, len);
+    if (answer == NULL) {
+        printf("day %u part %u: error: %s\n", day, part, aoc_last_error());
+        failures++;
+        return;
+    }
+    if (strcmp(answer, expected) != 0) {
+        printf("day %u part %u: expected %s, got %s\n", day, part, expected, answer);
+        failures++;
+    }
+    aoc_free_string(answer);
+}
+
+static void expect_error(uint32_t day, uint32_t part, const char *input, size_t len) {
+    char *answer = aoc_solve(2023, day, part, (const uint8_t *)input, len);
+    if (answer != NULL) {
+        printf("day %u part %u: expected an error, got %s\n", day, part, answer);
+        aoc_free_string(answer);
+        failures++;
+    } else if (aoc_last_error() == NULL) {
+        printf("day %u part %u: failed without an error message\n", day, part);
+        failures++;
+    }
+}
+
+int main(void) {
+    expect_answer(6, 1, EXAMPLE, strlen(EXAMPLE), "288");
+    expect_answer(6, 2, EXAMPLE, strlen(EXAMPLE), "71503");
+
+    expect_error(25, 1, EXAMPLE, strlen(EXAMPLE));
+    expect_error(6, 3, EXAMPLE, strlen(EXAMPLE));
+    expect_error(6, 1, "Time: 7", 7);
+    expect_error(6, 1, "\xff\xfe", 2);
+    expect_error(6, 1, NULL, 1);
+
+    aoc_free_string(NULL);
+
+    if (failures == 0) {
+        printf("ok\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,48 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Directory holding `libaoc_ffi`, next to the `deps` directory of this test.
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent()
+        .and_then(Path::parent)
+        .expect("Test binary is not in a target directory")
+        .to_path_buf()
+}
+
+/// Compiles `tests/c/solve.c` against the cdylib with the system C compiler
+/// and runs it.
+#[test]
+fn test_c_program() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lib_dir = library_dir();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("solve");
+
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .arg(crate_dir.join("tests/c/solve.c"))
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-laoc_ffi")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("Could not run the C compiler");
+    assert!(status.success(), "Could not compile the C test program");
+
+    let output = Command::new(&program).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "C test program failed:\n{}",
+        stdout
+    );
+    assert_eq!(stdout.trim(), "ok");
+}
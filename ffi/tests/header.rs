@@ -0,0 +1,26 @@
+use std::{fs, path::Path};
+
+/// Regenerates `include/aoc.h` and fails if it was out of date, so the
+/// committed header always matches the library.
+#[test]
+fn test_header_is_up_to_date() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+    let mut header = Vec::new();
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("Could not generate the header")
+        .write(&mut header);
+
+    let path = crate_dir.join("include/aoc.h");
+    let committed = fs::read(&path).unwrap_or_default();
+    if committed != header {
+        fs::write(&path, &header).unwrap();
+        panic!(
+            "{} was out of date and has been regenerated",
+            path.display()
+        );
+    }
+}
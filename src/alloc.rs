@@ -0,0 +1,179 @@
+//! Counting global allocator behind `--allocs`. Counters are kept per thread,
+//! so the days that `all` runs in parallel do not count each other's
+//! allocations, and are only updated once counting is enabled.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+pub struct Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    // Signed, memory can be freed on another thread than it was allocated.
+    current: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters { allocations: 0, bytes: 0, current: 0, peak: 0 })
+    };
+}
+
+/// Records an allocation of `size` bytes, or a release when `allocated` is
+/// false. Must not allocate itself.
+fn record(size: usize, allocated: bool) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // usize is at most 64 bits on every target we build for.
+    let size = size as i64;
+    let _ = COUNTERS.try_with(|c| {
+        let mut counters = c.get();
+        if allocated {
+            counters.allocations += 1;
+            counters.bytes += size as u64;
+            counters.current += size;
+            counters.peak = counters.peak.max(counters.current);
+        } else {
+            counters.current -= size;
+        }
+        c.set(counters);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), true);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), true);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(layout.size(), false);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(layout.size(), false);
+            record(new_size, true);
+        }
+        new_ptr
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Allocations made while running one step. `peak` is the most memory held at
+/// once on top of what was already allocated when the step started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// Byte count in the largest binary unit that keeps it above 1.
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: &[&str] = &["KiB", "MiB", "GiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit + 1 < UNITS.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Runs `f` and counts its allocations on this thread, `None` unless counting
+/// is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+
+    let before = COUNTERS.with(|c| {
+        let mut counters = c.get();
+        counters.peak = counters.current;
+        c.set(counters);
+        counters
+    });
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+
+    let stats = Stats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: u64::try_from(after.peak - before.current).unwrap_or(0),
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        enable();
+        let (v, stats) = measure(|| {
+            let big = vec![0u8; 4096];
+            drop(big);
+            Vec::<u64>::with_capacity(16)
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(v.capacity(), 16);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 4096 + 128);
+        assert_eq!(stats.peak, 4096);
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 30).to_string(), "3.0 GiB");
+    }
+}
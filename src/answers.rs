@@ -0,0 +1,121 @@
+//! Known answers and time budgets, recorded per year in
+//! `input/<year>/answers.txt`. Each line holds a day, its budget in
+//! milliseconds and the answer of each part in order, separated by
+//! whitespace. `#` starts a comment.
+//!
+//! ```text
+//! # day  budget_ms  part1      part2
+//! 5      20         318728750  37384986
+//! ```
+
+use std::{fs, time::Duration};
+
+use anyhow::{bail, Context};
+
+/// What a day of one year is expected to produce, and how fast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    pub day: u32,
+    /// Limit for parsing plus solving every part, in a release build.
+    pub budget: Duration,
+    /// Answers of parts 1, 2, ... in order.
+    pub answers: Vec<String>,
+}
+
+impl Recorded {
+    pub fn answer(&self, part: u32) -> Option<&str> {
+        let index = usize::try_from(part).ok()?.checked_sub(1)?;
+        self.answers.get(index).map(String::as_str)
+    }
+}
+
+pub fn path(year: u32) -> String {
+    format!("input/{}/answers.txt", year)
+}
+
+/// Reads the answers of `year`, relative to the working directory.
+pub fn load(year: u32) -> anyhow::Result<Vec<Recorded>> {
+    let path = path(year);
+    let text = fs::read_to_string(&path).with_context(|| format!("Could not read {}", path))?;
+    parse(&text).with_context(|| format!("Invalid answers in {}", path))
+}
+
+pub fn parse(text: &str) -> anyhow::Result<Vec<Recorded>> {
+    let mut recorded = Vec::<Recorded>::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut fields = line.split_whitespace();
+        let Some(day) = fields.next() else {
+            continue;
+        };
+
+        let line = i + 1;
+        let day = day
+            .parse::<u32>()
+            .with_context(|| format!("Invalid day on line {}", line))?;
+        let budget = fields
+            .next()
+            .with_context(|| format!("Missing budget on line {}", line))?
+            .parse::<u64>()
+            .with_context(|| format!("Invalid budget on line {}", line))?;
+        let answers = fields.map(str::to_string).collect::<Vec<_>>();
+        if answers.is_empty() {
+            bail!("Missing answers on line {}", line);
+        }
+        if recorded.iter().any(|r| r.day == day) {
+            bail!("Day {} is recorded twice", day);
+        }
+
+        recorded.push(Recorded {
+            day,
+            budget: Duration::from_millis(budget),
+            answers,
+        });
+    }
+
+    Ok(recorded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let recorded = parse("# day budget answers\n\n5 20 35 46 # example\n6\t1 288\n").unwrap();
+
+        assert_eq!(
+            recorded,
+            vec![
+                Recorded {
+                    day: 5,
+                    budget: Duration::from_millis(20),
+                    answers: vec!["35".to_string(), "46".to_string()],
+                },
+                Recorded {
+                    day: 6,
+                    budget: Duration::from_millis(1),
+                    answers: vec!["288".to_string()],
+                },
+            ]
+        );
+        assert_eq!(recorded[0].answer(2), Some("46"));
+        assert_eq!(recorded[0].answer(0), None);
+        assert_eq!(recorded[1].answer(2), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("5").is_err());
+        assert!(parse("5 20").is_err());
+        assert!(parse("x 20 1").is_err());
+        assert!(parse("5 20 1\n5 20 2").is_err());
+    }
+
+    #[test]
+    fn test_recorded_years_load() {
+        for year in crate::YEARS {
+            load(year.year).unwrap();
+        }
+    }
+}
@@ -1,13 +1,119 @@
-use aoc_runner_derive::aoc_lib;
+//! The solutions of every year behind one registry, for the runner and other
+//! tools. Each year lives in its own crate (`aoc2023`, ...) so cargo-aoc keeps
+//! working on it, and shares its helpers through `aoc-common`.
 
-pub mod day01;
-pub mod day02;
-pub mod day04;
-pub mod day05;
-pub mod day06;
+pub mod answers;
 
+use std::panic::{self, AssertUnwindSafe};
 
-// extern crate aoc_runner;
-// extern crate aoc_runner_derive;
+use anyhow::{anyhow, Context};
 
-aoc_lib! { year = 2023 }
+pub use aoc_common::answer::Answer;
+pub use aoc_common::registry::{Day, Solver, Year};
+
+/// Every year with solutions, oldest first.
+pub static YEARS: &[&Year] = &[&aoc2023::registry::YEAR];
+
+pub fn find_year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().copied().find(|y| y.year == year)
+}
+
+pub fn latest_year() -> &'static Year {
+    YEARS.last().expect("No years registered")
+}
+
+/// One implemented part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+}
+
+/// Every implemented part of every year, in order.
+pub fn puzzles() -> Vec<Puzzle> {
+    YEARS
+        .iter()
+        .flat_map(|year| {
+            year.days.iter().flat_map(move |day| {
+                day.parts().into_iter().map(move |part| Puzzle {
+                    year: year.year,
+                    day: day.day,
+                    part,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Parses `input` and solves one part with its default solver. Unknown
+/// puzzles, malformed input and panics in the solution are all errors.
+pub fn solve(year: u32, day: u32, part: u32, input: &str) -> anyhow::Result<Answer> {
+    let year = find_year(year).with_context(|| format!("Year {} has no solutions", year))?;
+    let day = year
+        .find(day)
+        .with_context(|| format!("Day {} of {} is not implemented", day, year.year))?;
+    let solver = day
+        .variants(part)
+        .next()
+        .with_context(|| format!("Day {} has no part {}", day.day, part))?;
+
+    let parsed = catch_panic(|| (day.parse)(input))??;
+    catch_panic(|| (solver.solve)(parsed.as_ref()))
+}
+
+/// Runs `f`, turning a panic into an error carrying its message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> anyhow::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        anyhow!("panicked: {}", message)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_years_are_sorted() {
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+    }
+
+    #[test]
+    fn test_find_year() {
+        assert_eq!(find_year(2023).map(|y| y.year), Some(2023));
+        assert!(find_year(2015).is_none());
+    }
+
+    #[test]
+    fn test_puzzles() {
+        let puzzles = puzzles();
+        assert!(puzzles.contains(&Puzzle {
+            year: 2023,
+            day: 6,
+            part: 2
+        }));
+        assert!(puzzles
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day, w[0].part) < (w[1].year, w[1].day, w[1].part)));
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+        assert_eq!(solve(2023, 6, 1, input).unwrap(), 288u64);
+        assert_eq!(solve(2023, 6, 2, input).unwrap(), 71503u64);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert!(solve(2015, 1, 1, "").is_err());
+        assert!(solve(2023, 25, 1, "").is_err());
+        assert!(solve(2023, 6, 3, "").is_err());
+        assert!(solve(2023, 6, 1, "Time: 7").is_err());
+    }
+}
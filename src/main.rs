@@ -0,0 +1,694 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{self, BufReader, IsTerminal},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{catch_panic, Day, Solver, Year};
+use alloc::Stats;
+use anyhow::{bail, Context};
+use aoc_common::visual::{RenderConfig, Renderer};
+use serde_json::json;
+
+mod alloc;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+const USAGE: &str = "\
+Usage: advent-of-code <command> [options]
+
+Commands:
+  list                       List every year, day, part and variant
+  run <day> [part]           Run the default variant of each part
+  all                        Run every day in parallel and print a summary table
+  check [day]                Run every variant and check they agree
+  bench <day> [part]         Time every variant side by side
+  visualise <day>            Render a day's solution at work in the terminal, frame by frame
+  watch <day>                Re-run the example tests and the real input of a day on every
+                             change to its source, input, example or recorded answers
+
+Options:
+  --year <year>              Select the year (default: every year for list, all and check,
+                             the latest one otherwise)
+  --variant <name>           Run this named variant instead of the default (run)
+  --input <path>             Read the input from <path> instead of input/<year>/dayN.txt
+  --iterations <n>           Number of timed runs per variant (bench, default 10)
+  --json                     Print one JSON object per day and part (run, all)
+  --threads <n>              Number of worker threads (all, default: available cores)
+  --explain                  Print what the generator and solvers do, step by step (run)
+  --allocs                   Count allocations, bytes and peak memory of each step (run, all)
+  --stream                   Solve straight from the input file, line by line, for days that
+                             support it (run)
+  --fps <n>                  Frames per second, 0 for as fast as possible (visualise, default 10)
+  --frames <dir>             Also write every frame as a text file in <dir> (visualise)";
+
+const COMMANDS: &[&str] = &["list", "run", "all", "check", "bench", "visualise", "watch"];
+
+struct Options {
+    command: String,
+    year: Option<u32>,
+    day: Option<u32>,
+    part: Option<u32>,
+    variant: Option<String>,
+    input: Option<String>,
+    iterations: u32,
+    json: bool,
+    threads: Option<usize>,
+    explain: bool,
+    allocs: bool,
+    stream: bool,
+    fps: u32,
+    frames: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<Options> {
+    let command = args.next().context("Missing command")?;
+    if !COMMANDS.contains(&command.as_str()) {
+        bail!("Unknown command {}", command);
+    }
+    let mut options = Options {
+        command,
+        year: None,
+        day: None,
+        part: None,
+        variant: None,
+        input: None,
+        iterations: 10,
+        json: false,
+        threads: None,
+        explain: false,
+        allocs: false,
+        stream: false,
+        fps: 10,
+        frames: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .with_context(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--year" => options.year = Some(value()?.parse().context("Invalid year")?),
+            "--variant" => options.variant = Some(value()?),
+            "--json" => options.json = true,
+            "--explain" => options.explain = true,
+            "--allocs" => options.allocs = true,
+            "--stream" => options.stream = true,
+            "--fps" => options.fps = value()?.parse().context("Invalid fps")?,
+            "--frames" => options.frames = Some(value()?),
+            "--threads" => options.threads = Some(value()?.parse().context("Invalid threads")?),
+            "--input" => options.input = Some(value()?),
+            "--iterations" => {
+                options.iterations = value()?.parse().context("Invalid iterations")?
+            }
+            _ if options.day.is_none() => options.day = Some(arg.parse().context("Invalid day")?),
+            _ if options.part.is_none() => {
+                options.part = Some(arg.parse().context("Invalid part")?)
+            }
+            _ => bail!("Unexpected argument {}", arg),
+        }
+    }
+
+    if options.explain && options.command != "run" {
+        bail!("--explain can only be used with run");
+    }
+    if options.allocs && !["run", "all"].contains(&options.command.as_str()) {
+        bail!("--allocs can only be used with run and all");
+    }
+    if options.stream && (options.command != "run" || options.variant.is_some()) {
+        bail!("--stream can only be used with run, without --variant");
+    }
+
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Error: {:#}\n\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.allocs {
+        alloc::enable();
+    }
+    if options.explain {
+        tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .without_time()
+            .init();
+    }
+
+    let result = match options.command.as_str() {
+        "list" => list(&options),
+        "run" => run(&options),
+        "all" => all(&options),
+        "check" => check(&options),
+        "bench" => bench(&options),
+        "visualise" => visualise(&options),
+        "watch" => watch(&options),
+        _ => unreachable!(),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list(options: &Options) -> anyhow::Result<()> {
+    for year in selected_years(options)? {
+        for day in year.days {
+            for solver in day.solvers {
+                println!("{} - Day {} - {}", year.year, day.day, solver.label());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The year given with `--year`, or every year.
+fn selected_years(options: &Options) -> anyhow::Result<Vec<&'static Year>> {
+    match options.year {
+        Some(_) => Ok(vec![selected_year(options)?]),
+        None => Ok(advent_of_code::YEARS.to_vec()),
+    }
+}
+
+/// The year given with `--year`, or the latest one.
+fn selected_year(options: &Options) -> anyhow::Result<&'static Year> {
+    match options.year {
+        Some(year) => advent_of_code::find_year(year)
+            .with_context(|| format!("Year {} has no solutions", year)),
+        None => Ok(advent_of_code::latest_year()),
+    }
+}
+
+fn selected_day(options: &Options) -> anyhow::Result<(&'static Year, &'static Day)> {
+    let year = selected_year(options)?;
+    let day = options.day.context("Missing day")?;
+    let day = year
+        .find(day)
+        .with_context(|| format!("Day {} of {} is not implemented", day, year.year))?;
+    Ok((year, day))
+}
+
+fn selected_parts(day: &Day, options: &Options) -> anyhow::Result<Vec<u32>> {
+    match options.part {
+        Some(part) if day.parts().contains(&part) => Ok(vec![part]),
+        Some(part) => bail!("Day {} has no part {}", day.day, part),
+        None => Ok(day.parts()),
+    }
+}
+
+fn input_path(year: &Year, day: &Day, options: &Options) -> String {
+    match &options.input {
+        Some(path) => path.clone(),
+        None => format!("input/{}/day{}.txt", year.year, day.day),
+    }
+}
+
+fn read_input(year: &Year, day: &Day, options: &Options) -> anyhow::Result<String> {
+    let path = input_path(year, day, options);
+    fs::read_to_string(&path).with_context(|| format!("Could not read input {}", path))
+}
+
+/// Result of running one solver, including the failures that stopped it.
+struct Outcome {
+    year: u32,
+    day: u32,
+    part: u32,
+    variant: Option<&'static str>,
+    answer: Option<String>,
+    parse_time: Option<Duration>,
+    solve_time: Option<Duration>,
+    parse_allocs: Option<Stats>,
+    solve_allocs: Option<Stats>,
+    error: Option<String>,
+    /// Skipped by `all` because the day's input is not checked out.
+    missing_input: bool,
+}
+
+impl Outcome {
+    /// An outcome with nothing run yet.
+    fn new(year: &Year, day: &Day, part: u32, variant: Option<&'static str>) -> Self {
+        Self {
+            year: year.year,
+            day: day.day,
+            part,
+            variant,
+            answer: None,
+            parse_time: None,
+            solve_time: None,
+            parse_allocs: None,
+            solve_allocs: None,
+            error: None,
+            missing_input: false,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self.variant {
+            Some(name) => format!(
+                "{} - Day {} - Part {} - {}",
+                self.year, self.day, self.part, name
+            ),
+            None => format!("{} - Day {} - Part {}", self.year, self.day, self.part),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        let allocs = |stats: Option<Stats>| {
+            stats.map(
+                |s| json!({"allocations": s.allocations, "bytes": s.bytes, "peak_bytes": s.peak}),
+            )
+        };
+        let mut json = json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "variant": self.variant,
+            "answer": self.answer,
+            "parse_time_ns": self.parse_time.map(|t| t.as_nanos() as u64),
+            "solve_time_ns": self.solve_time.map(|t| t.as_nanos() as u64),
+            "error": self.error,
+        });
+        // Only with --allocs, so the usual output keeps its shape.
+        if self.parse_allocs.is_some() || self.solve_allocs.is_some() {
+            json["parse_allocs"] = json!(allocs(self.parse_allocs));
+            json["solve_allocs"] = json!(allocs(self.solve_allocs));
+        }
+        if self.missing_input {
+            json["missing_input"] = json!(true);
+        }
+        json
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => {
+                write!(f, "{}: {} (", self.label(), answer)?;
+                if let Some(parse_time) = self.parse_time {
+                    write!(f, "parse {:?}, ", parse_time)?;
+                }
+                write!(f, "solve {:?})", self.solve_time.unwrap_or_default())?;
+                match (self.parse_allocs, self.solve_allocs) {
+                    (Some(parse), Some(solve)) => write!(f, " [parse {}; solve {}]", parse, solve),
+                    (None, Some(solve)) => write!(f, " [solve {}]", solve),
+                    _ => Ok(()),
+                }
+            }
+            (None, Some(error)) => write!(f, "{}: error: {}", self.label(), error),
+            (None, None) if self.missing_input => write!(f, "{}: no input", self.label()),
+            (None, None) => write!(f, "{}: no answer", self.label()),
+        }
+    }
+}
+
+/// Parses `input` once and runs `solvers` on it. Errors and panics are
+/// reported in the outcomes instead of aborting the other parts.
+fn solve_day(
+    year: &Year,
+    day: &Day,
+    input: anyhow::Result<String>,
+    solvers: &[&Solver],
+) -> Vec<Outcome> {
+    let _span = tracing::info_span!("day", year = year.year, day = day.day).entered();
+    let start = Instant::now();
+    let (parsed, parse_allocs) = tracing::info_span!("parse").in_scope(|| match input {
+        Ok(input) => alloc::measure(|| catch_panic(|| (day.parse)(&input)).and_then(|p| p)),
+        Err(e) => (Err(e), None),
+    });
+    let parse_time = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let error = format!("{:#}", e);
+            return solvers
+                .iter()
+                .map(|s| Outcome {
+                    error: Some(error.clone()),
+                    ..Outcome::new(year, day, s.part, s.name)
+                })
+                .collect();
+        }
+    };
+
+    solvers
+        .iter()
+        .map(|s| {
+            let _span = tracing::info_span!("part", part = s.part, variant = s.name).entered();
+            let start = Instant::now();
+            let (answer, solve_allocs) =
+                alloc::measure(|| catch_panic(|| (s.solve)(parsed.as_ref())));
+            let solve_time = start.elapsed();
+            match &answer {
+                Ok(answer) => tracing::info!(%answer, "solved"),
+                Err(e) => tracing::error!("{:#}", e),
+            }
+            Outcome {
+                parse_time: Some(parse_time),
+                solve_time: Some(solve_time),
+                parse_allocs,
+                solve_allocs,
+                error: answer.as_ref().err().map(|e| format!("{:#}", e)),
+                answer: answer.ok().map(|a| a.to_string()),
+                ..Outcome::new(year, day, s.part, s.name)
+            }
+        })
+        .collect()
+}
+
+fn print_outcomes(outcomes: &[Outcome], options: &Options) {
+    for outcome in outcomes {
+        match options.json {
+            true => println!("{}", outcome.to_json()),
+            false => println!("{}", outcome),
+        }
+    }
+}
+
+fn run(options: &Options) -> anyhow::Result<()> {
+    let (year, day) = selected_day(options)?;
+    if options.stream {
+        let parts = selected_parts(day, options)?;
+        let outcomes = stream_day(year, day, &input_path(year, day, options), &parts)?;
+        print_outcomes(&outcomes, options);
+        return match outcomes.iter().filter(|o| o.error.is_some()).count() {
+            0 => Ok(()),
+            failed => bail!("{} parts failed", failed),
+        };
+    }
+
+    let solvers = selected_parts(day, options)?
+        .into_iter()
+        .map(|part| {
+            day.variants(part)
+                .find(|s| s.name == options.variant.as_deref())
+                .with_context(|| format!("Day {} part {} has no such variant", day.day, part))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let outcomes = solve_day(year, day, read_input(year, day, options), &solvers);
+    print_outcomes(&outcomes, options);
+
+    match outcomes.iter().filter(|o| o.error.is_some()).count() {
+        0 => Ok(()),
+        failed => bail!("{} parts failed", failed),
+    }
+}
+
+/// Solves `parts` straight from the input file, line by line, without ever
+/// loading it whole. Reported as the `stream` variant, with no parse step.
+fn stream_day(year: &Year, day: &Day, path: &str, parts: &[u32]) -> anyhow::Result<Vec<Outcome>> {
+    let _span = tracing::info_span!("day", year = year.year, day = day.day).entered();
+    parts
+        .iter()
+        .map(|&part| {
+            let stream = day
+                .stream(part)
+                .with_context(|| format!("Day {} part {} cannot be streamed", day.day, part))?;
+            let file =
+                File::open(path).with_context(|| format!("Could not open input {}", path))?;
+            let mut reader = BufReader::new(file);
+
+            let _span = tracing::info_span!("part", part, variant = "stream").entered();
+            let start = Instant::now();
+            let (answer, solve_allocs) =
+                alloc::measure(|| catch_panic(|| (stream.solve)(&mut reader)).and_then(|a| a));
+            let solve_time = start.elapsed();
+
+            Ok(Outcome {
+                answer: answer.as_ref().ok().map(|a| a.to_string()),
+                solve_time: Some(solve_time),
+                solve_allocs,
+                error: answer.err().map(|e| format!("{:#}", e)),
+                ..Outcome::new(year, day, part, Some("stream"))
+            })
+        })
+        .collect()
+}
+
+/// Runs the default variants of every registered day on a pool of worker
+/// threads. Results are sorted by year, day and part before printing, so only
+/// the timings change between runs. Days whose input is not checked out are
+/// listed as such rather than failing.
+fn all(options: &Options) -> anyhow::Result<()> {
+    if options.input.is_some() {
+        bail!("--input cannot be used with all, every day reads its own input");
+    }
+
+    let days = selected_years(options)?
+        .into_iter()
+        .flat_map(|year| year.days.iter().map(move |day| (year, day)))
+        .collect::<Vec<_>>();
+    let threads = options
+        .threads
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1)
+        .clamp(1, days.len().max(1));
+
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let mut outcomes = thread::scope(|scope| {
+        let workers = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut outcomes = Vec::new();
+                    while let Some((year, day)) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let solvers = day
+                            .solvers
+                            .iter()
+                            .filter(|s| s.name.is_none())
+                            .collect::<Vec<_>>();
+                        if !Path::new(&input_path(year, day, options)).exists() {
+                            outcomes.extend(solvers.iter().map(|s| Outcome {
+                                missing_input: true,
+                                ..Outcome::new(year, day, s.part, s.name)
+                            }));
+                            continue;
+                        }
+                        let input = read_input(year, day, options);
+                        outcomes.extend(solve_day(year, day, input, &solvers));
+                    }
+                    outcomes
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().expect("Worker thread panicked"))
+            .collect::<Vec<_>>()
+    });
+    let wall_time = start.elapsed();
+    outcomes.sort_by_key(|o| (o.year, o.day, o.part));
+
+    match options.json {
+        true => print_outcomes(&outcomes, options),
+        false => print_table(&outcomes, wall_time),
+    }
+
+    match outcomes.iter().filter(|o| o.error.is_some()).count() {
+        0 => Ok(()),
+        failed => bail!("{} parts failed", failed),
+    }
+}
+
+fn print_table(outcomes: &[Outcome], wall_time: Duration) {
+    let allocs = outcomes.iter().any(|o| o.solve_allocs.is_some());
+    print!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:>12}  {:>12}",
+        "Year", "Day", "Part", "Answer", "Parse", "Solve"
+    );
+    match allocs {
+        true => println!("  {:<36}  Solve allocs", "Parse allocs"),
+        false => println!(),
+    }
+
+    let mut total = Duration::ZERO;
+    for (i, o) in outcomes.iter().enumerate() {
+        // Every part of a day shares the same parse, only count it once.
+        let first_part = i == 0 || (outcomes[i - 1].year, outcomes[i - 1].day) != (o.year, o.day);
+        if first_part {
+            total += o.parse_time.unwrap_or_default();
+        }
+        total += o.solve_time.unwrap_or_default();
+
+        let answer = match (&o.answer, &o.error) {
+            (Some(answer), _) => answer.clone(),
+            // Errors can be long, they are listed in full after the table.
+            (None, Some(_)) => "error, see below".to_string(),
+            (None, None) if o.missing_input => "no input".to_string(),
+            (None, None) => String::new(),
+        };
+        let time = |t: Option<Duration>| t.map(|t| format!("{:?}", t)).unwrap_or_default();
+        print!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:>12}  {:>12}",
+            o.year,
+            o.day,
+            o.part,
+            answer,
+            match first_part {
+                true => time(o.parse_time),
+                false => String::new(),
+            },
+            time(o.solve_time)
+        );
+        if allocs {
+            let stats = |s: Option<Stats>| s.map(|s| s.to_string()).unwrap_or_default();
+            println!(
+                "  {:<36}  {}",
+                match first_part {
+                    true => stats(o.parse_allocs),
+                    false => String::new(),
+                },
+                stats(o.solve_allocs)
+            );
+        } else {
+            println!();
+        }
+    }
+
+    println!(
+        "Total: {:?} of parsing and solving, {:?} wall clock",
+        total, wall_time
+    );
+
+    for o in outcomes {
+        if let Some(error) = &o.error {
+            println!("{}: {}", o.label(), error);
+        }
+    }
+}
+
+fn check(options: &Options) -> anyhow::Result<()> {
+    let days = match options.day {
+        Some(_) => vec![selected_day(options)?],
+        None => selected_years(options)?
+            .into_iter()
+            .flat_map(|year| year.days.iter().map(move |day| (year, day)))
+            .collect(),
+    };
+
+    let mut mismatches = 0;
+    for (year, day) in days {
+        let input = read_input(year, day, options)?;
+        let parsed = catch_panic(|| (day.parse)(&input)).and_then(|p| p)?;
+        for part in day.parts() {
+            // A panicking variant is reported as an error, and never agrees.
+            let answers = day
+                .variants(part)
+                .map(|s| (s, catch_panic(|| (s.solve)(parsed.as_ref()))))
+                .collect::<Vec<_>>();
+            let agree = answers
+                .iter()
+                .all(|(_, a)| matches!((a, &answers[0].1), (Ok(a), Ok(first)) if a == first));
+            if !agree {
+                mismatches += 1;
+            }
+
+            for (solver, answer) in &answers {
+                let status = if agree { "ok" } else { "MISMATCH" };
+                let answer = match answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(e) => format!("error: {:#}", e),
+                };
+                println!(
+                    "{} - Day {} - {}: {} [{}]",
+                    year.year,
+                    day.day,
+                    solver.label(),
+                    answer,
+                    status
+                );
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        bail!("{} parts have variants that disagree or fail", mismatches);
+    }
+    Ok(())
+}
+
+fn bench(options: &Options) -> anyhow::Result<()> {
+    let (year, day) = selected_day(options)?;
+    let input = read_input(year, day, options)?;
+    let parsed = (day.parse)(&input)?;
+    let iterations = options.iterations.max(1);
+
+    for part in selected_parts(day, options)? {
+        for solver in day.variants(part) {
+            let timings = (0..iterations)
+                .map(|_| time(solver, parsed.as_ref()))
+                .collect::<Vec<_>>();
+            let min = timings.iter().min().unwrap();
+            let mean = timings.iter().sum::<Duration>() / iterations;
+            println!(
+                "{} - Day {} - {:<20} min {:>12?}  mean {:>12?}",
+                year.year,
+                day.day,
+                solver.label(),
+                min,
+                mean
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn time(solver: &Solver, parsed: &dyn std::any::Any) -> Duration {
+    let start = Instant::now();
+    std::hint::black_box((solver.solve)(parsed));
+    start.elapsed()
+}
+
+fn watch(options: &Options) -> anyhow::Result<()> {
+    let (year, day) = selected_day(options)?;
+    watch::watch(&watch::Watched {
+        year: year.year,
+        day: day.day,
+        input: input_path(year, day, options),
+    })
+}
+
+fn visualise(options: &Options) -> anyhow::Result<()> {
+    let (year, day) = selected_day(options)?;
+    let visualise = day
+        .visualise
+        .with_context(|| format!("Day {} has no visualisation", day.day))?;
+    let parsed = (day.parse)(&read_input(year, day, options)?)?;
+
+    let config = RenderConfig {
+        fps: Some(options.fps),
+        ansi: io::stdout().is_terminal(),
+        dump_dir: options.frames.as_ref().map(PathBuf::from),
+    };
+    let mut renderer = Renderer::new(config, Some(Box::new(io::stdout())))
+        .context("Could not create the frames directory")?;
+    visualise(parsed.as_ref(), &mut renderer)?;
+    eprintln!("{} frames", renderer.frames());
+
+    Ok(())
+}
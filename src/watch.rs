@@ -0,0 +1,172 @@
+//! `watch`: re-runs a day's example tests and real solve whenever its source,
+//! input, example or recorded answers change. The runner itself is stale as
+//! soon as the source changes, so both go through cargo.
+
+use std::{
+    fs,
+    path::PathBuf,
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use advent_of_code::answers::{self, Recorded};
+use anyhow::Context;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct Watched {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+}
+
+impl Watched {
+    fn files(&self) -> Vec<PathBuf> {
+        vec![
+            PathBuf::from(format!("aoc{}/src/day{:02}.rs", self.year, self.day)),
+            PathBuf::from(format!(
+                "aoc{}/fuzz/corpus/day{:02}/example",
+                self.year, self.day
+            )),
+            PathBuf::from(&self.input),
+            PathBuf::from(answers::path(self.year)),
+        ]
+    }
+}
+
+/// Polls the watched files forever, re-running on every change.
+pub fn watch(watched: &Watched) -> anyhow::Result<()> {
+    let files = watched.files();
+    let mut last = None;
+    loop {
+        let modified = files.iter().map(modified).collect::<Vec<_>>();
+        if last.as_ref() != Some(&modified) {
+            if last.is_some() {
+                println!();
+            }
+            println!("Running {} day {}", watched.year, watched.day);
+            examples(watched)?;
+            solve(watched)?;
+            println!("Watching {} files, Ctrl-C to stop", files.len());
+            last = Some(modified);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn examples(watched: &Watched) -> anyhow::Result<()> {
+    let output = Command::new("cargo")
+        .args(["test", "-q", "-p", &format!("aoc{}", watched.year), "--lib"])
+        .arg(format!("day{:02}::", watched.day))
+        .output()
+        .context("Could not run cargo test")?;
+
+    if output.status.success() {
+        println!("Examples: ok");
+    } else {
+        println!("Examples: FAILED");
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+        print!("{}", String::from_utf8_lossy(&output.stderr));
+    }
+    Ok(())
+}
+
+fn solve(watched: &Watched) -> anyhow::Result<()> {
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "-q",
+            "--release",
+            "-p",
+            "advent-of-code",
+            "--",
+            "run",
+        ])
+        .arg(watched.day.to_string())
+        .args([
+            "--year",
+            &watched.year.to_string(),
+            "--input",
+            &watched.input,
+        ])
+        .arg("--json")
+        .output()
+        .context("Could not run cargo run")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        println!("Real input: FAILED");
+        print!("{}", String::from_utf8_lossy(&output.stderr));
+        return Ok(());
+    }
+
+    // Answers can be missing or unreadable while they are being edited.
+    let recorded = answers::load(watched.year)
+        .ok()
+        .and_then(|r| r.into_iter().find(|r| r.day == watched.day));
+    for line in stdout.lines() {
+        let outcome = serde_json::from_str::<serde_json::Value>(line)
+            .with_context(|| format!("Unexpected runner output {}", line))?;
+        println!("{}", compare(&outcome, recorded.as_ref()));
+    }
+    Ok(())
+}
+
+/// One line per part: the answer and whether it matches the recorded one.
+fn compare(outcome: &serde_json::Value, recorded: Option<&Recorded>) -> String {
+    let part = outcome["part"].as_u64().unwrap_or_default();
+    let expected = u32::try_from(part)
+        .ok()
+        .and_then(|p| recorded.and_then(|r| r.answer(p)));
+    let status = match (outcome["answer"].as_str(), expected) {
+        (None, _) => format!(
+            "error: {}",
+            outcome["error"].as_str().unwrap_or("no answer")
+        ),
+        (Some(answer), Some(expected)) if answer == expected => format!("{} [ok]", answer),
+        (Some(answer), Some(expected)) => {
+            format!("{} [MISMATCH, expected {}]", answer, expected)
+        }
+        (Some(answer), None) => format!("{} [not recorded]", answer),
+    };
+    format!("Part {}: {}", part, status)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compare() {
+        let recorded = answers::parse("6 5 288 71503").unwrap();
+        let recorded = recorded.first();
+        let outcome = |part: u32, answer: Option<&str>| json!({"part": part, "answer": answer, "error": answer.map_or(Some("bad input"), |_| None)});
+
+        assert_eq!(
+            compare(&outcome(1, Some("288")), recorded),
+            "Part 1: 288 [ok]"
+        );
+        assert_eq!(
+            compare(&outcome(2, Some("7")), recorded),
+            "Part 2: 7 [MISMATCH, expected 71503]"
+        );
+        assert_eq!(
+            compare(&outcome(3, Some("1")), recorded),
+            "Part 3: 1 [not recorded]"
+        );
+        assert_eq!(
+            compare(&outcome(1, None), recorded),
+            "Part 1: error: bad input"
+        );
+        assert_eq!(
+            compare(&outcome(1, Some("288")), None),
+            "Part 1: 288 [not recorded]"
+        );
+    }
+}
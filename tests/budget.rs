@@ -0,0 +1,61 @@
+//! Runs every recorded day on its real input and checks its answers and time
+//! budget, see `input/<year>/answers.txt`.
+
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{answers, YEARS};
+
+/// Best of a few runs, so one descheduling does not fail the budget.
+const RUNS: usize = 3;
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    ignore = "budgets hold for release builds, run with cargo test --release"
+)]
+fn test_days_within_budget() {
+    let mut failures = Vec::new();
+    for year in YEARS {
+        for recorded in answers::load(year.year).unwrap() {
+            let day = year.find(recorded.day).unwrap_or_else(|| {
+                panic!("Day {} of {} is not implemented", recorded.day, year.year)
+            });
+            let path = format!("input/{}/day{}.txt", year.year, day.day);
+            let Ok(input) = fs::read_to_string(&path) else {
+                eprintln!("Skipping {}, {} is missing", year.year, path);
+                continue;
+            };
+
+            let mut best = Duration::MAX;
+            for _ in 0..RUNS {
+                let start = Instant::now();
+                let parsed = (day.parse)(&input).unwrap();
+                for part in day.parts() {
+                    let solver = day.variants(part).next().unwrap();
+                    let answer = (solver.solve)(parsed.as_ref()).to_string();
+                    assert_eq!(
+                        Some(answer.as_str()),
+                        recorded.answer(part),
+                        "{} day {} part {}",
+                        year.year,
+                        day.day,
+                        part
+                    );
+                }
+                best = best.min(start.elapsed());
+            }
+
+            if best > recorded.budget {
+                failures.push(format!(
+                    "{} day {} took {:?}, over its {:?} budget",
+                    year.year, day.day, best, recorded.budget
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}